@@ -0,0 +1,127 @@
+//! Derive macros for the extended-typenum crate. They are re-exported by extended-typenum with the `derive` feature.
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, DeriveInput, GenericParam, LitStr};
+
+/// Derives `TypeDisplay` for a type generic over typenum types.
+///
+/// The type is formatted as its name followed by its type and const parameters, like `Matrix<3, 4>`.
+/// Lifetimes are skipped. Each displayed type parameter must implement `TypeDisplay`.
+/// Const parameters are integers, formatted with the radix of the options, `bool`s or `char`s.
+///
+/// Attributes:
+/// - `#[type_display(rename = "Name")]` on the type replaces its name.
+/// - `#[type_display(rename = "label")]` on a parameter prefixes it with `label = `.
+/// - `#[type_display(hide)]` on a parameter skips it, and doesn't require it to implement `TypeDisplay`.
+#[proc_macro_derive(TypeDisplay, attributes(type_display))]
+pub fn derive_type_display(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Options given by a `#[type_display(...)]` attribute.
+#[derive(Default)]
+struct Options {
+    rename: Option<LitStr>,
+    hide: bool,
+}
+
+fn parse_options(attrs: &[Attribute]) -> syn::Result<Options> {
+    let mut options = Options::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("type_display")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                options.rename = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("hide") {
+                options.hide = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `rename` or `hide`"))
+            }
+        })?;
+    }
+    Ok(options)
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let name = match parse_options(&input.attrs)?.rename {
+        Some(rename) => rename.value(),
+        None => ident.to_string(),
+    };
+
+    let mut params = Vec::new();
+    let mut bounds = Vec::new();
+    for param in input.generics.params.iter_mut() {
+        let (attrs, displayed) = match param {
+            GenericParam::Lifetime(_) => continue,
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                (&mut param.attrs, quote!(<#ident as ::extended_typenum::TypeDisplay>::fmt_with(f, format)?;))
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                (&mut param.attrs, quote!(::extended_typenum::PrivateConstDisplay::fmt_const(&#ident, f, format)?;))
+            }
+        };
+        let options = parse_options(attrs)?;
+        // Helper attributes are not allowed on the generics of the impl.
+        attrs.retain(|attr| !attr.path().is_ident("type_display"));
+        if options.hide {
+            continue;
+        }
+        if let GenericParam::Type(param) = param {
+            let ident = &param.ident;
+            bounds.push(quote!(#ident: ::extended_typenum::TypeDisplay));
+        }
+        let label = options.rename.map(|rename| format!("{} = ", rename.value()));
+        params.push(match label {
+            Some(label) => quote!(f.write_str(#label)?; #displayed),
+            None => displayed,
+        });
+    }
+
+    let separated = params.iter().enumerate().map(|(i, param)| {
+        if i == 0 {
+            quote!(#param)
+        } else {
+            quote!(f.write_str(", ")?; #param)
+        }
+    });
+    let body = if params.is_empty() {
+        quote!(f.write_str(#name))
+    } else {
+        quote! {
+            f.write_str(#name)?;
+            f.write_str("<")?;
+            #(#separated)*
+            f.write_str(">")
+        }
+    };
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.into_iter().flat_map(|clause| clause.predicates.iter());
+    Ok(quote! {
+        impl #impl_generics ::extended_typenum::TypeDisplay for #ident #type_generics
+        where
+            #(#predicates,)*
+            #(#bounds,)*
+        {
+            fn fmt(f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                <Self as ::extended_typenum::TypeDisplay>::fmt_with(f, &::extended_typenum::TypeFormat::new())
+            }
+
+            fn fmt_with(
+                f: &mut ::std::fmt::Formatter<'_>,
+                format: &::extended_typenum::TypeFormat,
+            ) -> ::std::fmt::Result {
+                let _ = format;
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,32 @@
+//! Derives `TypeDisplay` through the `derive` feature of extended-typenum.
+
+use std::marker::PhantomData;
+
+use extended_typenum::*;
+
+#[derive(TypeDisplay)]
+struct Matrix<Rows, Cols>(PhantomData<(Rows, Cols)>);
+
+#[derive(TypeDisplay)]
+#[type_display(rename = "Q")]
+struct Quantity<'a, #[type_display(rename = "scale")] Scale, #[type_display(hide)] T, const EXP: i32> {
+    value: PhantomData<&'a T>,
+    scale: PhantomData<Scale>,
+}
+
+#[derive(TypeDisplay)]
+struct Flags<const ON: bool, const C: char, const BIG: u128>;
+
+#[test]
+fn names_and_parameters() {
+    assert_eq!(type_string::<Matrix<U3, Sum<U2, U2>>>(), "Matrix<3, 4>");
+    assert_eq!(type_string::<Quantity<rational!(P1, U1000), f64, 2>>(), "Q<scale = 1/1000, 2>");
+    assert_eq!(type_string::<Flags<true, 'x', { u128::MAX }>>(), "Flags<true, x, 340282366920938463463374607431768211455>");
+}
+
+#[test]
+fn radix() {
+    assert_eq!(format!("{:x}", DisplayType::<Matrix<U255, U16>>::new()), "Matrix<ff, 10>");
+    assert_eq!(format!("{:X}", DisplayType::<Quantity<U10, f64, -30>>::new()), "Q<scale = A, -1E>");
+    assert_eq!(format!("{:b}", DisplayType::<Flags<false, '2', 5>>::new()), "Flags<false, 2, 101>");
+}
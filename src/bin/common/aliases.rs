@@ -0,0 +1,52 @@
+//! The typenum aliases shared by `typenum-calc` and `typenum-lint`.
+
+/// Greatest common divisor, `0` only when both numbers are `0`.
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Whether typenum defines the `U{n}` alias: up to 1024, 3600, and the powers of 10 and the powers of 2 and their
+/// predecessors fitting in a `u64`.
+fn has_alias(n: u128) -> bool {
+    let power_of_ten = (0..=19).any(|k| 10u128.pow(k) == n);
+    let near_power_of_two = n <= 1 << 63 && (n.is_power_of_two() || (n + 1).is_power_of_two());
+    n <= 1024 || n == 3600 || power_of_ten || near_power_of_two
+}
+
+/// `U{n}` when typenum defines it, else the expanded type.
+pub fn unsigned_alias(n: u128) -> String {
+    if has_alias(n) {
+        format!("U{n}")
+    } else {
+        unsigned_type(n)
+    }
+}
+
+/// `Z0`, `P{n}` or `N{n}` when typenum defines it, else the expanded type. These aliases stop at `i64::MAX`.
+pub fn integer_alias(n: i128) -> String {
+    match n {
+        0 => "Z0".to_string(),
+        n if n.unsigned_abs() > i64::MAX as u128 || !has_alias(n.unsigned_abs()) => integer_type(n),
+        n if n > 0 => format!("P{n}"),
+        n => format!("N{}", n.unsigned_abs()),
+    }
+}
+
+pub fn unsigned_type(n: u128) -> String {
+    if n == 0 {
+        "UTerm".to_string()
+    } else {
+        format!("UInt<{}, B{}>", unsigned_type(n >> 1), n & 1)
+    }
+}
+
+pub fn integer_type(n: i128) -> String {
+    match n {
+        0 => "Z0".to_string(),
+        n if n > 0 => format!("PInt<{}>", unsigned_type(n.unsigned_abs())),
+        n => format!("NInt<{}>", unsigned_type(n.unsigned_abs())),
+    }
+}
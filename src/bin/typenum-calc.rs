@@ -0,0 +1,359 @@
+//! Evaluates an arithmetic expression like the type operators of the crate, and prints the type of the result.
+//!
+//! ```text
+//! typenum-calc [--alias | --type] [--steps] <expression>
+//! ```
+//!
+//! - By default, the value of the result is printed, like [TypeDisplay](extended_typenum::TypeDisplay) does: `17/4`.
+//! - `--alias` prints the canonical spelling: `rational!(P17, U4)`, `P3`.
+//! - `--type` prints the fully expanded type: `R<PInt<UInt<...>>, UInt<...>>`.
+//! - `--steps` also prints the intermediate results.
+//!
+//! The expression is made of `+`, `-`, `*`, `/`, `%`, `^` (power) and parentheses over:
+//! - plain numbers like `7`, which are rationals, so that `-7/12` is a fraction;
+//! - the typenum aliases `U7`, `P7`, `N7`, `Z0`, `B0` and `B1`, which are integers;
+//! - `rational!(A, B)` and `rational!(A)`.
+//!
+//! As with [CrossInt](extended_typenum::CrossInt) and [CrossRational](extended_typenum::CrossRational),
+//! the left operand decides the semantics: when it is an integer, the right operand is converted into an integer
+//! and the division and remainder are truncated toward zero, `P7 / 2 = 3`. Multiplying an integer by a rational `N/D`
+//! multiplies it by `N` and then divides it by `D`, and dividing it does the opposite, `P7 * (3/2) = 10`.
+//! When the left operand is a rational, the right operand is converted into a rational and the result is exact,
+//! `rational!(P7) / 2 = 7/2`.
+
+use std::fmt::Write as _;
+use std::process::ExitCode;
+
+#[path = "common/aliases.rs"]
+mod aliases;
+
+use aliases::{gcd, integer_alias, integer_type, unsigned_alias, unsigned_type};
+
+fn main() -> ExitCode {
+    let mut format = Format::Value;
+    let mut steps = false;
+    let mut expression = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--alias" => format = Format::Alias,
+            "--type" => format = Format::Type,
+            "--value" => format = Format::Value,
+            "--steps" => steps = true,
+            "-h" | "--help" => {
+                println!("Usage: typenum-calc [--value | --alias | --type] [--steps] <expression>");
+                return ExitCode::SUCCESS;
+            }
+            _ => expression.push(arg),
+        }
+    }
+    match evaluate(&expression.join(" "), format) {
+        Ok((result, lines)) => {
+            if steps {
+                for line in lines {
+                    println!("{line}");
+                }
+            }
+            println!("{}", format.apply(&result));
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// How results are printed.
+#[derive(Clone, Copy)]
+enum Format {
+    Value,
+    Alias,
+    Type,
+}
+
+impl Format {
+    fn apply(self, number: &Number) -> String {
+        match self {
+            Format::Value => number.value(),
+            Format::Alias => number.alias(),
+            Format::Type => number.expanded(),
+        }
+    }
+}
+
+/// Evaluates an expression, giving the result and the intermediate steps.
+fn evaluate(expression: &str, format: Format) -> Result<(Number, Vec<String>), String> {
+    let mut parser = Parser { tokens: tokenize(expression)?, pos: 0, format, steps: Vec::new() };
+    let result = parser.expression()?;
+    match parser.tokens.get(parser.pos) {
+        None => Ok((result, parser.steps)),
+        Some(token) => Err(format!("unexpected `{token}`")),
+    }
+}
+
+/// An exact number, with a simplified fraction.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Number {
+    /// Whether it behaves as an integer, like [CrossInt](extended_typenum::CrossInt).
+    integer: bool,
+    num: i128,
+    den: i128,
+}
+
+impl Number {
+    fn integer(value: i128) -> Self {
+        Number { integer: true, num: value, den: 1 }
+    }
+
+    fn rational(num: i128, den: i128) -> Result<Self, String> {
+        if den == 0 {
+            return Err("division by zero".to_string());
+        }
+        let negative = (num < 0) != (den < 0);
+        let gcd = gcd(num.unsigned_abs(), den.unsigned_abs());
+        let (magnitude, den) = (num.unsigned_abs() / gcd, den.unsigned_abs() / gcd);
+        // The magnitude of `i128::MIN` only fits as a negative numerator.
+        let num = if negative { 0i128.checked_sub_unsigned(magnitude) } else { i128::try_from(magnitude).ok() };
+        match (num, i128::try_from(den)) {
+            (Some(num), Ok(den)) => Ok(Number { integer: false, num, den }),
+            _ => Err("overflow".to_string()),
+        }
+    }
+
+    /// Conversion with [IntoInteger](extended_typenum::IntoInteger), only implemented for integral numbers.
+    fn as_integer(self) -> Result<i128, String> {
+        if self.den == 1 {
+            Ok(self.num)
+        } else {
+            Err(format!("{} is not an integer", self.value()))
+        }
+    }
+
+    fn apply(self, op: char, rhs: Number) -> Result<Number, String> {
+        let overflow = || "overflow".to_string();
+        if self.integer {
+            let a = self.num;
+            // Like `CrossInt`, `I * (N / D)` is `I * N / D` and `I / (N / D)` is `I * D / N`.
+            let (mul, div) = match op {
+                '*' if !rhs.integer => (rhs.num, rhs.den),
+                '/' if !rhs.integer => (rhs.den, rhs.num),
+                _ => (1, 1),
+            };
+            if (mul, div) != (1, 1) {
+                if div == 0 {
+                    return Err("division by zero".to_string());
+                }
+                let result = a.checked_mul(mul).and_then(|product| product.checked_div(div));
+                return result.map(Number::integer).ok_or_else(overflow);
+            }
+            let b = rhs.as_integer()?;
+            let result = match op {
+                '+' => a.checked_add(b),
+                '-' => a.checked_sub(b),
+                '*' => a.checked_mul(b),
+                '/' | '%' if b == 0 => return Err("division by zero".to_string()),
+                '/' => a.checked_div(b),
+                '%' => a.checked_rem(b),
+                '^' => match (a, b) {
+                    // Like typenum, the powers of `0`, `1` and `-1` include the negative ones.
+                    (-1, b) => Some(if b % 2 == 0 { 1 } else { -1 }),
+                    (0 | 1, b) if b != 0 => Some(a),
+                    (a, b) if b < 0 => return Err(format!("negative exponent {b} for the integer {a}")),
+                    (a, b) => u32::try_from(b).ok().and_then(|b| a.checked_pow(b)),
+                },
+                _ => unreachable!(),
+            };
+            result.map(Number::integer).ok_or_else(overflow)
+        } else {
+            let (a, b) = ((self.num, self.den), (rhs.num, rhs.den));
+            match op {
+                '+' | '-' => {
+                    let right = b.0.checked_mul(a.1).ok_or_else(overflow)?;
+                    let left = a.0.checked_mul(b.1).ok_or_else(overflow)?;
+                    let num = if op == '+' { left.checked_add(right) } else { left.checked_sub(right) };
+                    Number::rational(num.ok_or_else(overflow)?, a.1.checked_mul(b.1).ok_or_else(overflow)?)
+                }
+                '*' => Number::rational(
+                    a.0.checked_mul(b.0).ok_or_else(overflow)?,
+                    a.1.checked_mul(b.1).ok_or_else(overflow)?,
+                ),
+                '/' => Number::rational(
+                    a.0.checked_mul(b.1).ok_or_else(overflow)?,
+                    a.1.checked_mul(b.0).ok_or_else(overflow)?,
+                ),
+                '%' => Err("the remainder is not implemented for rationals".to_string()),
+                '^' => {
+                    let exponent = rhs.as_integer()?;
+                    let (num, den) = if exponent < 0 { (a.1, a.0) } else { a };
+                    let exponent = u32::try_from(exponent.unsigned_abs()).map_err(|_| overflow())?;
+                    Number::rational(
+                        num.checked_pow(exponent).ok_or_else(overflow)?,
+                        den.checked_pow(exponent).ok_or_else(overflow)?,
+                    )
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    /// Same as [TypeDisplay](extended_typenum::TypeDisplay).
+    fn value(&self) -> String {
+        if self.den == 1 {
+            self.num.to_string()
+        } else {
+            format!("{}/{}", self.num, self.den)
+        }
+    }
+
+    fn alias(&self) -> String {
+        match (self.integer, self.den) {
+            (true, _) => integer_alias(self.num),
+            (false, 1) => format!("rational!({})", integer_alias(self.num)),
+            (false, den) => format!("rational!({}, {})", integer_alias(self.num), unsigned_alias(den.unsigned_abs())),
+        }
+    }
+
+    fn expanded(&self) -> String {
+        if self.integer {
+            integer_type(self.num)
+        } else {
+            format!("R<{}, {}>", integer_type(self.num), unsigned_type(self.den.unsigned_abs()))
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphanumeric() || **c == '_' || **c == '!') {
+                token.push(c);
+                chars.next();
+            }
+            tokens.push(token);
+        } else if "+-*/%^(),".contains(c) {
+            tokens.push(c.to_string());
+            chars.next();
+        } else {
+            return Err(format!("unexpected character `{c}`"));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser, evaluating while parsing.
+struct Parser {
+    tokens: Vec<String>,
+    pos: usize,
+    format: Format,
+    steps: Vec<String>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        match self.peek() {
+            Some(t) if t == token => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(t) => Err(format!("expected `{token}`, found `{t}`")),
+            None => Err(format!("expected `{token}`")),
+        }
+    }
+
+    fn binary(&mut self, lhs: Number, op: char, rhs: Number) -> Result<Number, String> {
+        let result = lhs.apply(op, rhs)?;
+        let mut step = String::new();
+        let format = self.format;
+        let _ = write!(step, "{} {op} {} = {}", format.apply(&lhs), format.apply(&rhs), format.apply(&result));
+        self.steps.push(step);
+        Ok(result)
+    }
+
+    /// `term (('+' | '-') term)*`
+    fn expression(&mut self) -> Result<Number, String> {
+        let mut lhs = self.term()?;
+        while let Some(op @ ("+" | "-")) = self.peek() {
+            let op = op.chars().next().unwrap();
+            self.pos += 1;
+            let rhs = self.term()?;
+            lhs = self.binary(lhs, op, rhs)?;
+        }
+        Ok(lhs)
+    }
+
+    /// `unary (('*' | '/' | '%') unary)*`
+    fn term(&mut self) -> Result<Number, String> {
+        let mut lhs = self.unary()?;
+        while let Some(op @ ("*" | "/" | "%")) = self.peek() {
+            let op = op.chars().next().unwrap();
+            self.pos += 1;
+            let rhs = self.unary()?;
+            lhs = self.binary(lhs, op, rhs)?;
+        }
+        Ok(lhs)
+    }
+
+    /// `'-' unary | atom ('^' unary)?`
+    fn unary(&mut self) -> Result<Number, String> {
+        if self.peek() == Some("-") {
+            self.pos += 1;
+            let value = self.unary()?;
+            let num = value.num.checked_neg().ok_or("overflow")?;
+            return Ok(Number { num, ..value });
+        }
+        let base = self.atom()?;
+        if self.peek() == Some("^") {
+            self.pos += 1;
+            let exponent = self.unary()?;
+            return self.binary(base, '^', exponent);
+        }
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<Number, String> {
+        let token = self.peek().ok_or("unexpected end of expression")?.to_string();
+        self.pos += 1;
+        if token == "(" {
+            let value = self.expression()?;
+            self.expect(")")?;
+            return Ok(value);
+        }
+        if token == "rational!" {
+            self.expect("(")?;
+            let num = self.expression()?;
+            let value = if self.peek() == Some(",") {
+                self.pos += 1;
+                let den = self.expression()?.as_integer()?;
+                if den <= 0 {
+                    return Err(format!("the denominator {den} is not a positive unsigned number"));
+                }
+                Number::rational(num.as_integer()?, den)?
+            } else {
+                Number { integer: false, ..num }
+            };
+            self.expect(")")?;
+            return Ok(value);
+        }
+        if let Ok(value) = token.parse::<i128>() {
+            return Number::rational(value, 1);
+        }
+        let parse = |digits: &str| digits.parse::<i128>().map_err(|_| format!("unknown name `{token}`"));
+        match token.split_at(1) {
+            ("U", "0") => Ok(Number::integer(0)),
+            ("U" | "P", digits) if !digits.starts_with('0') => parse(digits).map(Number::integer),
+            ("N", digits) if !digits.starts_with('0') => parse(digits).map(|n| Number::integer(-n)),
+            _ if token == "Z0" || token == "B0" => Ok(Number::integer(0)),
+            _ if token == "B1" => Ok(Number::integer(1)),
+            _ => Err(format!("unknown name `{token}`")),
+        }
+    }
+}
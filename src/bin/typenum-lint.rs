@@ -0,0 +1,350 @@
+//! Finds the rationals written by hand as `R<N, D>` with typenum aliases which are not simplified, like `R<P6, U8>`.
+//!
+//! ```text
+//! typenum-lint [--fix] [<path>...]
+//! ```
+//!
+//! The Rust files of the given files and directories (the current directory by default) are scanned,
+//! skipping the `target` and hidden directories, the comments and the literals. Only `R` and `extended_typenum::...::R`
+//! with the aliases `P{n}`, `N{n}` or `Z0` then `U{n}` as arguments are checked, and each non-simplified one is reported
+//! with the [rational!](macro@extended_typenum::rational) form to use instead:
+//! ```text
+//! src/units.rs:12:17: `R<P6, U8>` is not simplified, use `rational!(P3, U4)`
+//! ```
+//!
+//! The reduced numbers which typenum has no alias for are written as expanded `PInt<UInt<...>>` and `UInt<...>` types.
+//!
+//! With `--fix`, they are rewritten in place, and the `rational!` macro and the typenum types must then be in scope.
+//! The exit status is a failure when an issue is left.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[path = "common/aliases.rs"]
+mod aliases;
+
+use aliases::{gcd, integer_alias, unsigned_alias};
+
+fn main() -> ExitCode {
+    let mut fix = false;
+    let mut paths = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--fix" => fix = true,
+            "-h" | "--help" => {
+                println!("Usage: typenum-lint [--fix] [<path>...]");
+                return ExitCode::SUCCESS;
+            }
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    if paths.is_empty() {
+        paths.push(PathBuf::from("."));
+    }
+
+    let mut files = Vec::new();
+    for path in &paths {
+        if let Err(error) = collect_files(path, &mut files) {
+            eprintln!("error: {}: {error}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut success = true;
+    for file in files {
+        let source = match fs::read_to_string(&file) {
+            Ok(source) => source,
+            Err(error) => {
+                eprintln!("error: {}: {error}", file.display());
+                success = false;
+                continue;
+            }
+        };
+        let issues = find_issues(&source);
+        for issue in &issues {
+            let location = format!("{}:{}:{}", file.display(), issue.line, issue.column);
+            match &issue.replacement {
+                Some(replacement) if fix => println!("{location}: fixed `{}` into `{replacement}`", issue.text),
+                Some(replacement) => println!("{location}: `{}` is not simplified, use `{replacement}`", issue.text),
+                None => println!("{location}: `{}` has a zero denominator", issue.text),
+            }
+            success &= fix && issue.replacement.is_some();
+        }
+        if fix && issues.iter().any(|issue| issue.replacement.is_some()) {
+            if let Err(error) = fs::write(&file, apply_fixes(&source, &issues)) {
+                eprintln!("error: {}: {error}", file.display());
+                success = false;
+            }
+        }
+    }
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Collects the Rust files of a path, sorted so that the reports are stable.
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        fs::metadata(path)?;
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    let mut entries = fs::read_dir(path)?.map(|entry| entry.map(|entry| entry.path())).collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    for entry in entries {
+        let name = entry.file_name().unwrap_or_default().to_string_lossy();
+        if entry.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                collect_files(&entry, files)?;
+            }
+        } else if name.ends_with(".rs") {
+            files.push(entry);
+        }
+    }
+    Ok(())
+}
+
+/// A non-simplified `R<N, D>`.
+struct Issue {
+    line: usize,
+    column: usize,
+    /// Byte range in the source.
+    start: usize,
+    end: usize,
+    /// The type, with normalized spacing.
+    text: String,
+    /// `None` when the denominator is zero.
+    replacement: Option<String>,
+}
+
+fn apply_fixes(source: &str, issues: &[Issue]) -> String {
+    let mut result = String::with_capacity(source.len());
+    let mut last = 0;
+    for issue in issues {
+        if let Some(replacement) = &issue.replacement {
+            result.push_str(&source[last..issue.start]);
+            result.push_str(replacement);
+            last = issue.end;
+        }
+    }
+    result.push_str(&source[last..]);
+    result
+}
+
+/// Scans the source token by token, skipping the comments and the literals.
+fn find_issues(source: &str) -> Vec<Issue> {
+    let mut scanner = Scanner { source, pos: 0 };
+    let mut issues = Vec::new();
+    while let Some(c) = scanner.peek() {
+        match c {
+            '/' if scanner.rest().starts_with("//") => scanner.skip_line(),
+            '/' if scanner.rest().starts_with("/*") => scanner.skip_block_comment(),
+            '"' => scanner.skip_string(0),
+            '\'' => scanner.skip_quote(),
+            c if is_ident_start(c) => {
+                let start = scanner.pos;
+                let path = scanner.path();
+                if matches!(path.as_str(), "r" | "br" | "cr") && matches!(scanner.peek(), Some('"' | '#')) {
+                    scanner.skip_raw_string();
+                } else if matches!(path.as_str(), "b" | "c") && scanner.peek() == Some('"') {
+                    scanner.skip_string(0);
+                } else if path == "b" && scanner.peek() == Some('\'') {
+                    scanner.skip_quote();
+                } else if let Some(issue) = scanner.rational(start, &path) {
+                    issues.push(issue);
+                }
+            }
+            c => scanner.pos += c.len_utf8(),
+        }
+    }
+    issues
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn rest(&self) -> &str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let found = self.rest().starts_with(s);
+        if found {
+            self.pos += s.len();
+        }
+        found
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_line(&mut self) {
+        self.pos = self.rest().find('\n').map_or(self.source.len(), |i| self.pos + i);
+    }
+
+    /// Block comments can be nested.
+    fn skip_block_comment(&mut self) {
+        let mut depth = 0;
+        while let Some(c) = self.peek() {
+            if self.eat("/*") {
+                depth += 1;
+            } else if self.eat("*/") {
+                depth -= 1;
+                if depth == 0 {
+                    return;
+                }
+            } else {
+                self.pos += c.len_utf8();
+            }
+        }
+    }
+
+    /// Skips a string starting at the opening quote, ended by a quote followed by `hashes` `#`.
+    fn skip_string(&mut self, hashes: usize) {
+        let end = format!("\"{}", "#".repeat(hashes));
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+            if self.eat(&end) {
+                return;
+            }
+            self.pos += c.len_utf8();
+            // Raw strings have no escapes.
+            if c == '\\' && hashes == 0 {
+                self.pos += self.peek().map_or(0, char::len_utf8);
+            }
+        }
+    }
+
+    /// Skips the `#`s and the string after a `r` prefix.
+    fn skip_raw_string(&mut self) {
+        let hashes = self.rest().len() - self.rest().trim_start_matches('#').len();
+        self.pos += hashes;
+        if self.peek() == Some('"') {
+            self.skip_string(hashes);
+        }
+    }
+
+    /// Skips a character literal, or only the quote of a lifetime.
+    fn skip_quote(&mut self) {
+        self.pos += 1;
+        let mut chars = self.rest().chars();
+        match (chars.next(), chars.next()) {
+            (Some('\\'), _) => {
+                if let Some(end) = self.rest()[2..].find('\'') {
+                    self.pos += end + 3;
+                }
+            }
+            (Some(c), Some('\'')) => self.pos += c.len_utf8() + 1,
+            _ => {}
+        }
+    }
+
+    fn ident(&mut self) -> &str {
+        let start = self.pos;
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches(is_ident_continue).len();
+        &self.source[start..self.pos]
+    }
+
+    /// Reads a path like `extended_typenum::R`, without generic arguments.
+    fn path(&mut self) -> String {
+        let mut path = self.ident().to_string();
+        while self.rest().starts_with("::") && self.rest()[2..].starts_with(is_ident_start) {
+            self.pos += 2;
+            path.push_str("::");
+            path.push_str(self.ident());
+        }
+        path
+    }
+
+    /// Checks the generic arguments of `R` after its path. Other paths are left, so that their arguments are scanned.
+    fn rational(&mut self, start: usize, path: &str) -> Option<Issue> {
+        let (prefix, name) = path.rsplit_once("::").map_or(("", path), |(prefix, name)| (prefix, name));
+        if name != "R" || !(prefix.is_empty() || prefix.split("::").next() == Some("extended_typenum")) {
+            return None;
+        }
+        let after_path = self.pos;
+        let Some((n, numerator, d, denominator)) = self.arguments() else {
+            self.pos = after_path;
+            return None;
+        };
+        let divisor = gcd(n.unsigned_abs(), d);
+        if d != 0 && divisor == 1 {
+            return None;
+        }
+        let line = self.source[..start].matches('\n').count() + 1;
+        let line_start = self.source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let column = self.source[line_start..start].chars().count() + 1;
+        // The macro is exported at the root of the crate.
+        let macro_path = match prefix.split("::").next() {
+            Some("extended_typenum") => "extended_typenum::rational!",
+            _ => "rational!",
+        };
+        // The reduced numbers without typenum aliases are written as expanded types.
+        let replacement = (d != 0).then(|| match (integer_alias(n / divisor as i128), d / divisor) {
+            (n, 1) => format!("{macro_path}({n})"),
+            (n, d) => format!("{macro_path}({n}, {})", unsigned_alias(d)),
+        });
+        Some(Issue { line, column, start, end: self.pos, text: format!("{path}<{numerator}, {denominator}>"), replacement })
+    }
+
+    /// Reads `<N, D>` where `N` is an integer alias and `D` an unsigned alias.
+    fn arguments(&mut self) -> Option<(i128, String, u128, String)> {
+        self.skip_whitespace();
+        if !self.eat("<") {
+            return None;
+        }
+        self.skip_whitespace();
+        let numerator = self.ident().to_string();
+        let n = if numerator == "Z0" {
+            0
+        } else if let Some(digits) = numerator.strip_prefix('P') {
+            parse_alias_digits(digits)? as i128
+        } else {
+            -(parse_alias_digits(numerator.strip_prefix('N')?)? as i128)
+        };
+        self.skip_whitespace();
+        if !self.eat(",") {
+            return None;
+        }
+        self.skip_whitespace();
+        let denominator = self.ident().to_string();
+        let d = match denominator.as_str() {
+            "U0" => 0,
+            _ => parse_alias_digits(denominator.strip_prefix('U')?)?,
+        };
+        self.skip_whitespace();
+        if self.eat(",") {
+            self.skip_whitespace();
+        }
+        self.eat(">").then_some((n, numerator, d, denominator))
+    }
+}
+
+/// Digits of a non-zero alias like the `12` of `P12`.
+fn parse_alias_digits(digits: &str) -> Option<u128> {
+    if digits.starts_with('0') || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|&n| n <= i128::MAX as u128)
+}
@@ -0,0 +1,216 @@
+//! Rewrites the typenum types of rustc diagnostics into numbers, with [prettify_type_name].
+//!
+//! It reads human or JSON (`--message-format=json`) diagnostics on the standard input, and prints them on the standard output:
+//! ```text
+//! cargo build 2>&1 | typenum-pretty
+//! cargo build --message-format=json | typenum-pretty
+//! ```
+//!
+//! The source lines of the snippets are kept as is, so that the spans stay aligned.
+//! The lines which are whole JSON objects are JSON messages, in which only the `message`, `label` and `rendered` strings
+//! are rewritten, the rest is kept byte for byte. The lines which are not UTF-8 are kept as is.
+
+use std::io::{self, BufRead, Write};
+
+use extended_typenum::prettify_type_name;
+
+fn main() -> io::Result<()> {
+    match run() {
+        // The reader is gone, like `head`.
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    }
+}
+
+fn run() -> io::Result<()> {
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout().lock();
+    let mut line = Vec::new();
+    while stdin.read_until(b'\n', &mut line)? > 0 {
+        let Ok(text) = std::str::from_utf8(&line) else {
+            stdout.write_all(&line)?;
+            line.clear();
+            continue;
+        };
+        let (text, newline) = text.strip_suffix('\n').map_or((text, ""), |text| (text, "\n"));
+        if is_json_object(text) {
+            write!(stdout, "{}{newline}", prettify_json(text))?;
+        } else {
+            write!(stdout, "{}{newline}", prettify_human(text))?;
+        }
+        line.clear();
+    }
+    stdout.flush()
+}
+
+/// Rewrites human diagnostics, except the source lines like `12 |     let x: U6 = ...;`.
+fn prettify_human(text: &str) -> String {
+    text.split('\n')
+        .map(|line| if is_source_line(line) { line.to_string() } else { prettify_type_name(line) })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn is_source_line(line: &str) -> bool {
+    let line = line.trim_start();
+    let rest = line.trim_start_matches(|c: char| c.is_ascii_digit());
+    rest.len() < line.len() && rest.trim_start().starts_with('|')
+}
+
+fn is_json_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n')
+}
+
+/// Whether the line is a whole JSON object, like the messages of `--message-format=json`.
+fn is_json_object(line: &str) -> bool {
+    let line = line.trim_matches(is_json_whitespace);
+    line.starts_with('{') && json_value(line).is_some_and(str::is_empty)
+}
+
+/// Skips the JSON value at the start of `text`, returning the rest without its leading whitespace.
+fn json_value(text: &str) -> Option<&str> {
+    let text = text.trim_start_matches(is_json_whitespace);
+    let rest = match text.chars().next()? {
+        '"' => &text[string_literal_len(text)?..],
+        '{' => json_sequence(&text[1..], '}', json_member)?,
+        '[' => json_sequence(&text[1..], ']', json_value)?,
+        _ => {
+            let len = text.find(|c: char| !c.is_ascii_alphanumeric() && !"+-.".contains(c)).unwrap_or(text.len());
+            let literal = &text[..len];
+            let number = literal.starts_with(|c: char| c == '-' || c.is_ascii_digit()) && literal.parse::<f64>().is_ok();
+            if !number && !matches!(literal, "true" | "false" | "null") {
+                return None;
+            }
+            &text[len..]
+        }
+    };
+    Some(rest.trim_start_matches(is_json_whitespace))
+}
+
+/// Skips a `"key": value` member of an object.
+fn json_member(text: &str) -> Option<&str> {
+    let text = text.trim_start_matches(is_json_whitespace);
+    if !text.starts_with('"') {
+        return None;
+    }
+    let rest = text[string_literal_len(text)?..].trim_start_matches(is_json_whitespace);
+    json_value(rest.strip_prefix(':')?)
+}
+
+/// Skips the elements of an array or the members of an object after the opening bracket, and the closing one.
+fn json_sequence(text: &str, end: char, element: fn(&str) -> Option<&str>) -> Option<&str> {
+    let mut text = text.trim_start_matches(is_json_whitespace);
+    if let Some(rest) = text.strip_prefix(end) {
+        return Some(rest);
+    }
+    loop {
+        text = element(text)?;
+        match text.strip_prefix(',') {
+            Some(rest) => text = rest,
+            None => return text.strip_prefix(end),
+        }
+    }
+}
+
+/// Keys whose string values are rewritten.
+const REWRITTEN_KEYS: [&str; 3] = ["message", "label", "rendered"];
+
+/// Rewrites the strings of the [REWRITTEN_KEYS] in a JSON line, without parsing the whole document.
+fn prettify_json(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut key = None;
+    let mut rest = line;
+    while let Some(start) = rest.find('"') {
+        result.push_str(&rest[..start]);
+        let Some(len) = string_literal_len(&rest[start..]) else {
+            // Truncated line, keep it.
+            rest = &rest[start..];
+            break;
+        };
+        let literal = &rest[start..start + len];
+        rest = &rest[start + len..];
+        let value = decode_string(&literal[1..len - 1]);
+        if rest.trim_start().starts_with(':') {
+            result.push_str(literal);
+            key = Some(value);
+        } else if key.take().is_some_and(|key| REWRITTEN_KEYS.contains(&key.as_str())) {
+            let pretty = prettify_human(&value);
+            if pretty == value {
+                result.push_str(literal);
+            } else {
+                result.push_str(&encode_string(&pretty));
+            }
+        } else {
+            result.push_str(literal);
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Length in bytes of the string literal at the start of `text`, quotes included.
+fn string_literal_len(text: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn decode_string(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('b') => result.push('\u{8}'),
+            Some('f') => result.push('\u{c}'),
+            Some('u') => {
+                let mut code = hex_code(&mut chars);
+                // Surrogate pair.
+                if (0xD800..0xDC00).contains(&code) && chars.as_str().starts_with("\\u") {
+                    chars.nth(1);
+                    let low = hex_code(&mut chars);
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF);
+                }
+                result.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            Some(c) => result.push(c),
+            None => {}
+        }
+    }
+    result
+}
+
+fn hex_code(chars: &mut std::str::Chars<'_>) -> u32 {
+    chars.take(4).fold(0, |code, c| code * 16 + c.to_digit(16).unwrap_or(0))
+}
+
+fn encode_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
@@ -0,0 +1,264 @@
+//! Type aliases for constants.
+//!
+//! This module re-exports all of typenum's [consts](typenum::consts), and adds rational approximations of common irrational constants.
+//!
+//! Each constant is given by the terms of its continued fraction expansion, and a type alias taking an index `I`
+//! which gives the `I`-th [convergent](Convergent), starting at zero. Convergents are the best rational approximations:
+//! no rational with a smaller or equal denominator is closer to the constant.
+//!
+//! Enough terms are given for the last convergent to be the closest [f64] to the constant.
+//! Asking for a convergent past the last one fails to compile.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//! use extended_typenum::consts::*;
+//!
+//! assert_type_eq!(Pi<U3>, rational!(P355, U113));
+//! assert_type_eq!(Sqrt2<U3>, rational!(P17, U12));
+//!
+//! type Tau = Prod<Pi<U3>, rational!(P2)>;
+//! assert_type_eq!(Tau, rational!(P710, U113));
+//! ```
+
+pub use typenum::consts::*;
+
+use super::*;
+
+/// Terms of the continued fraction expansion of π.
+pub type PiTerms = tarr![U3, U7, U15, U1, U292, U1, U1, U1, U2, U1, U3, U1, U14, U2, U1];
+
+/// Best rational approximations of π: `Pi<I>` is the `I`-th convergent of [PiTerms].
+///
+/// Up to `Pi<U14>`, whose [Rational::F64] is the closest [f64] to π.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+/// use extended_typenum::consts::*;
+///
+/// assert_type_eq!(Pi<U0>, rational!(P3));
+/// assert_type_eq!(Pi<U1>, rational!(P22, U7));
+/// assert_type_eq!(Pi<U2>, rational!(P333, U106));
+/// assert_type_eq!(Pi<U3>, rational!(P355, U113));
+/// assert_type_eq!(Pi<U14>, Simplified<Pi<U14>>);
+///
+/// // Error of the f64 value of each convergent.
+/// let exact: f64 = core::f64::consts::PI;
+/// assert_eq!((<Pi<U0> as Rational>::F64 - exact).abs(), 0.14159265358979312);
+/// assert_eq!((<Pi<U1> as Rational>::F64 - exact).abs(), 0.0012644892673496777);
+/// assert_eq!((<Pi<U2> as Rational>::F64 - exact).abs(), 8.32196275291075e-05);
+/// assert_eq!((<Pi<U3> as Rational>::F64 - exact).abs(), 2.667641894049666e-07);
+/// assert_eq!((<Pi<U4> as Rational>::F64 - exact).abs(), 5.778906242426274e-10);
+/// assert_eq!((<Pi<U5> as Rational>::F64 - exact).abs(), 3.3162805834763276e-10);
+/// assert_eq!((<Pi<U6> as Rational>::F64 - exact).abs(), 1.2235634727630895e-10);
+/// assert_eq!((<Pi<U7> as Rational>::F64 - exact).abs(), 2.914335439641036e-11);
+/// assert_eq!((<Pi<U8> as Rational>::F64 - exact).abs(), 8.715250743307479e-12);
+/// assert_eq!((<Pi<U9> as Rational>::F64 - exact).abs(), 1.6107115641261771e-12);
+/// assert_eq!((<Pi<U10> as Rational>::F64 - exact).abs(), 4.04121180963557e-13);
+/// assert_eq!((<Pi<U11> as Rational>::F64 - exact).abs(), 2.220446049250313e-14);
+/// assert_eq!((<Pi<U12> as Rational>::F64 - exact).abs(), 4.440892098500626e-16);
+/// assert_eq!((<Pi<U13> as Rational>::F64 - exact).abs(), 4.440892098500626e-16);
+/// assert_eq!((<Pi<U14> as Rational>::F64 - exact).abs(), 0.0);
+/// ```
+pub type Pi<I> = NthConvergent<PiTerms, I>;
+
+/// Terms of the continued fraction expansion of e.
+pub type ETerms = tarr![U2, U1, U2, U1, U1, U4, U1, U1, U6, U1, U1, U8, U1, U1, U10, U1, U1, U12, U1, U1, U14];
+
+/// Best rational approximations of e: `E<I>` is the `I`-th convergent of [ETerms].
+///
+/// Up to `E<U20>`, whose [Rational::F64] is the closest [f64] to e.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+/// use extended_typenum::consts::*;
+///
+/// assert_type_eq!(E<U0>, rational!(P2));
+/// assert_type_eq!(E<U1>, rational!(P3));
+/// assert_type_eq!(E<U2>, rational!(P8, U3));
+/// assert_type_eq!(E<U3>, rational!(P11, U4));
+/// assert_type_eq!(E<U20>, Simplified<E<U20>>);
+///
+/// // Error of the f64 value of each convergent.
+/// let exact: f64 = core::f64::consts::E;
+/// assert_eq!((<E<U0> as Rational>::F64 - exact).abs(), 0.7182818284590451);
+/// assert_eq!((<E<U1> as Rational>::F64 - exact).abs(), 0.2817181715409549);
+/// assert_eq!((<E<U2> as Rational>::F64 - exact).abs(), 0.05161516179237857);
+/// assert_eq!((<E<U3> as Rational>::F64 - exact).abs(), 0.03171817154095491);
+/// assert_eq!((<E<U4> as Rational>::F64 - exact).abs(), 0.003996114173330678);
+/// assert_eq!((<E<U5> as Rational>::F64 - exact).abs(), 0.0004681715409549092);
+/// assert_eq!((<E<U6> as Rational>::F64 - exact).abs(), 0.0003331105103270282);
+/// assert_eq!((<E<U7> as Rational>::F64 - exact).abs(), 2.8030695884417867e-05);
+/// assert_eq!((<E<U8> as Rational>::F64 - exact).abs(), 2.2585665719887515e-06);
+/// assert_eq!((<E<U9> as Rational>::F64 - exact).abs(), 1.7536305070287028e-06);
+/// assert_eq!((<E<U10> as Rational>::F64 - exact).abs(), 1.1017732681750658e-07);
+/// assert_eq!((<E<U11> as Rational>::F64 - exact).abs(), 6.746947445179785e-09);
+/// assert_eq!((<E<U12> as Rational>::F64 - exact).abs(), 5.515095491404054e-09);
+/// assert_eq!((<E<U13> as Rational>::F64 - exact).abs(), 2.766507023466147e-10);
+/// assert_eq!((<E<U14> as Rational>::F64 - exact).abs(), 1.3643752794223474e-11);
+/// assert_eq!((<E<U15> as Rational>::F64 - exact).abs(), 1.1538769939534177e-11);
+/// assert_eq!((<E<U16> as Rational>::F64 - exact).abs(), 4.818367926873179e-13);
+/// assert_eq!((<E<U17> as Rational>::F64 - exact).abs(), 1.9984014443252818e-14);
+/// assert_eq!((<E<U18> as Rational>::F64 - exact).abs(), 1.687538997430238e-14);
+/// assert_eq!((<E<U19> as Rational>::F64 - exact).abs(), 8.881784197001252e-16);
+/// assert_eq!((<E<U20> as Rational>::F64 - exact).abs(), 0.0);
+/// ```
+pub type E<I> = NthConvergent<ETerms, I>;
+
+/// Terms of the continued fraction expansion of √2.
+pub type Sqrt2Terms = tarr![U1, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2, U2];
+
+/// Best rational approximations of √2: `Sqrt2<I>` is the `I`-th convergent of [Sqrt2Terms].
+///
+/// Up to `Sqrt2<U21>`, whose [Rational::F64] is the closest [f64] to √2.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+/// use extended_typenum::consts::*;
+///
+/// assert_type_eq!(Sqrt2<U0>, rational!(P1));
+/// assert_type_eq!(Sqrt2<U1>, rational!(P3, U2));
+/// assert_type_eq!(Sqrt2<U2>, rational!(P7, U5));
+/// assert_type_eq!(Sqrt2<U3>, rational!(P17, U12));
+/// assert_type_eq!(Sqrt2<U21>, Simplified<Sqrt2<U21>>);
+///
+/// // Error of the f64 value of each convergent.
+/// let exact: f64 = core::f64::consts::SQRT_2;
+/// assert_eq!((<Sqrt2<U0> as Rational>::F64 - exact).abs(), 0.41421356237309515);
+/// assert_eq!((<Sqrt2<U1> as Rational>::F64 - exact).abs(), 0.08578643762690485);
+/// assert_eq!((<Sqrt2<U2> as Rational>::F64 - exact).abs(), 0.014213562373095234);
+/// assert_eq!((<Sqrt2<U3> as Rational>::F64 - exact).abs(), 0.002453104293571595);
+/// assert_eq!((<Sqrt2<U4> as Rational>::F64 - exact).abs(), 0.00042045892481934466);
+/// assert_eq!((<Sqrt2<U5> as Rational>::F64 - exact).abs(), 7.215191261922271e-05);
+/// assert_eq!((<Sqrt2<U6> as Rational>::F64 - exact).abs(), 1.2378941142587863e-05);
+/// assert_eq!((<Sqrt2<U7> as Rational>::F64 - exact).abs(), 2.1239014147411694e-06);
+/// assert_eq!((<Sqrt2<U8> as Rational>::F64 - exact).abs(), 3.644035520000699e-07);
+/// assert_eq!((<Sqrt2<U9> as Rational>::F64 - exact).abs(), 6.252177442434004e-08);
+/// assert_eq!((<Sqrt2<U10> as Rational>::F64 - exact).abs(), 1.0727040367086715e-08);
+/// assert_eq!((<Sqrt2<U11> as Rational>::F64 - exact).abs(), 1.8404691104478843e-09);
+/// assert_eq!((<Sqrt2<U12> as Rational>::F64 - exact).abs(), 3.157747396898003e-10);
+/// assert_eq!((<Sqrt2<U13> as Rational>::F64 - exact).abs(), 5.4178217467892864e-11);
+/// assert_eq!((<Sqrt2<U14> as Rational>::F64 - exact).abs(), 9.29567534058151e-12);
+/// assert_eq!((<Sqrt2<U15> as Rational>::F64 - exact).abs(), 1.5947243525715749e-12);
+/// assert_eq!((<Sqrt2<U16> as Rational>::F64 - exact).abs(), 2.737809978725636e-13);
+/// assert_eq!((<Sqrt2<U17> as Rational>::F64 - exact).abs(), 4.6851411639181606e-14);
+/// assert_eq!((<Sqrt2<U18> as Rational>::F64 - exact).abs(), 8.215650382226158e-15);
+/// assert_eq!((<Sqrt2<U19> as Rational>::F64 - exact).abs(), 1.3322676295501878e-15);
+/// assert_eq!((<Sqrt2<U20> as Rational>::F64 - exact).abs(), 4.440892098500626e-16);
+/// assert_eq!((<Sqrt2<U21> as Rational>::F64 - exact).abs(), 0.0);
+/// ```
+pub type Sqrt2<I> = NthConvergent<Sqrt2Terms, I>;
+
+/// Terms of the continued fraction expansion of the golden ratio φ.
+pub type GoldenRatioTerms = tarr![U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1, U1];
+
+/// Best rational approximations of the golden ratio φ: `GoldenRatio<I>` is the `I`-th convergent of [GoldenRatioTerms].
+///
+/// Up to `GoldenRatio<U39>`, whose [Rational::F64] is the closest [f64] to the golden ratio φ.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+/// use extended_typenum::consts::*;
+///
+/// assert_type_eq!(GoldenRatio<U0>, rational!(P1));
+/// assert_type_eq!(GoldenRatio<U1>, rational!(P2));
+/// assert_type_eq!(GoldenRatio<U2>, rational!(P3, U2));
+/// assert_type_eq!(GoldenRatio<U3>, rational!(P5, U3));
+/// assert_type_eq!(GoldenRatio<U39>, Simplified<GoldenRatio<U39>>);
+///
+/// // Error of the f64 value of each convergent.
+/// let exact: f64 = (1.0 + 5f64.sqrt()) / 2.0;
+/// assert_eq!((<GoldenRatio<U0> as Rational>::F64 - exact).abs(), 0.6180339887498949);
+/// assert_eq!((<GoldenRatio<U1> as Rational>::F64 - exact).abs(), 0.3819660112501051);
+/// assert_eq!((<GoldenRatio<U2> as Rational>::F64 - exact).abs(), 0.1180339887498949);
+/// assert_eq!((<GoldenRatio<U3> as Rational>::F64 - exact).abs(), 0.04863267791677184);
+/// assert_eq!((<GoldenRatio<U4> as Rational>::F64 - exact).abs(), 0.018033988749894814);
+/// assert_eq!((<GoldenRatio<U5> as Rational>::F64 - exact).abs(), 0.0069660112501050975);
+/// assert_eq!((<GoldenRatio<U6> as Rational>::F64 - exact).abs(), 0.0026493733652794837);
+/// assert_eq!((<GoldenRatio<U7> as Rational>::F64 - exact).abs(), 0.0010136302977241662);
+/// assert_eq!((<GoldenRatio<U8> as Rational>::F64 - exact).abs(), 0.00038692992636546464);
+/// assert_eq!((<GoldenRatio<U9> as Rational>::F64 - exact).abs(), 0.00014782943192326314);
+/// assert_eq!((<GoldenRatio<U10> as Rational>::F64 - exact).abs(), 5.6460660007306984e-05);
+/// assert_eq!((<GoldenRatio<U11> as Rational>::F64 - exact).abs(), 2.15668056606777e-05);
+/// assert_eq!((<GoldenRatio<U12> as Rational>::F64 - exact).abs(), 8.237676933475768e-06);
+/// assert_eq!((<GoldenRatio<U13> as Rational>::F64 - exact).abs(), 3.1465286196574738e-06);
+/// assert_eq!((<GoldenRatio<U14> as Rational>::F64 - exact).abs(), 1.2018646489142526e-06);
+/// assert_eq!((<GoldenRatio<U15> as Rational>::F64 - exact).abs(), 4.590717870289751e-07);
+/// assert_eq!((<GoldenRatio<U16> as Rational>::F64 - exact).abs(), 1.7534976959332482e-07);
+/// assert_eq!((<GoldenRatio<U17> as Rational>::F64 - exact).abs(), 6.697765919660981e-08);
+/// assert_eq!((<GoldenRatio<U18> as Rational>::F64 - exact).abs(), 2.5583188456579364e-08);
+/// assert_eq!((<GoldenRatio<U19> as Rational>::F64 - exact).abs(), 9.771908393574336e-09);
+/// assert_eq!((<GoldenRatio<U20> as Rational>::F64 - exact).abs(), 3.732536946188247e-09);
+/// assert_eq!((<GoldenRatio<U21> as Rational>::F64 - exact).abs(), 1.4257022229458016e-09);
+/// assert_eq!((<GoldenRatio<U22> as Rational>::F64 - exact).abs(), 5.445699446937624e-10);
+/// assert_eq!((<GoldenRatio<U23> as Rational>::F64 - exact).abs(), 2.0800716704627575e-10);
+/// assert_eq!((<GoldenRatio<U24> as Rational>::F64 - exact).abs(), 7.945177848966978e-11);
+/// assert_eq!((<GoldenRatio<U25> as Rational>::F64 - exact).abs(), 3.034772433352373e-11);
+/// assert_eq!((<GoldenRatio<U26> as Rational>::F64 - exact).abs(), 1.159183860011126e-11);
+/// assert_eq!((<GoldenRatio<U27> as Rational>::F64 - exact).abs(), 4.427569422205124e-12);
+/// assert_eq!((<GoldenRatio<U28> as Rational>::F64 - exact).abs(), 1.6913137557139635e-12);
+/// assert_eq!((<GoldenRatio<U29> as Rational>::F64 - exact).abs(), 6.459277557269161e-13);
+/// assert_eq!((<GoldenRatio<U30> as Rational>::F64 - exact).abs(), 2.466915560717098e-13);
+/// assert_eq!((<GoldenRatio<U31> as Rational>::F64 - exact).abs(), 9.414691248821327e-14);
+/// assert_eq!((<GoldenRatio<U32> as Rational>::F64 - exact).abs(), 3.597122599785507e-14);
+/// assert_eq!((<GoldenRatio<U33> as Rational>::F64 - exact).abs(), 1.3766765505351941e-14);
+/// assert_eq!((<GoldenRatio<U34> as Rational>::F64 - exact).abs(), 5.329070518200751e-15);
+/// assert_eq!((<GoldenRatio<U35> as Rational>::F64 - exact).abs(), 1.9984014443252818e-15);
+/// assert_eq!((<GoldenRatio<U36> as Rational>::F64 - exact).abs(), 8.881784197001252e-16);
+/// assert_eq!((<GoldenRatio<U37> as Rational>::F64 - exact).abs(), 2.220446049250313e-16);
+/// assert_eq!((<GoldenRatio<U38> as Rational>::F64 - exact).abs(), 2.220446049250313e-16);
+/// assert_eq!((<GoldenRatio<U39> as Rational>::F64 - exact).abs(), 0.0);
+/// ```
+pub type GoldenRatio<I> = NthConvergent<GoldenRatioTerms, I>;
+
+/// Terms of the continued fraction expansion of ln(2).
+pub type Ln2Terms = tarr![U0, U1, U2, U3, U1, U6, U3, U1, U1, U2, U1, U1, U1, U1, U3, U10, U1, U1, U1, U2, U1, U1, U1, U1, U3];
+
+/// Best rational approximations of ln(2): `Ln2<I>` is the `I`-th convergent of [Ln2Terms].
+///
+/// Up to `Ln2<U24>`, whose [Rational::F64] is the closest [f64] to ln(2).
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+/// use extended_typenum::consts::*;
+///
+/// assert_type_eq!(Ln2<U0>, rational!(Z0));
+/// assert_type_eq!(Ln2<U1>, rational!(P1));
+/// assert_type_eq!(Ln2<U2>, rational!(P2, U3));
+/// assert_type_eq!(Ln2<U3>, rational!(P7, U10));
+/// assert_type_eq!(Ln2<U24>, Simplified<Ln2<U24>>);
+///
+/// // Error of the f64 value of each convergent.
+/// let exact: f64 = core::f64::consts::LN_2;
+/// assert_eq!((<Ln2<U0> as Rational>::F64 - exact).abs(), 0.6931471805599453);
+/// assert_eq!((<Ln2<U1> as Rational>::F64 - exact).abs(), 0.3068528194400547);
+/// assert_eq!((<Ln2<U2> as Rational>::F64 - exact).abs(), 0.026480513893278657);
+/// assert_eq!((<Ln2<U3> as Rational>::F64 - exact).abs(), 0.006852819440054669);
+/// assert_eq!((<Ln2<U4> as Rational>::F64 - exact).abs(), 0.0008394882522529956);
+/// assert_eq!((<Ln2<U5> as Rational>::F64 - exact).abs(), 3.4637621872946056e-05);
+/// assert_eq!((<Ln2<U6> as Rational>::F64 - exact).abs(), 6.386336118602287e-06);
+/// assert_eq!((<Ln2<U7> as Rational>::F64 - exact).abs(), 3.5043715616023263e-06);
+/// assert_eq!((<Ln2<U8> as Rational>::F64 - exact).abs(), 7.631144623987396e-07);
+/// assert_eq!((<Ln2<U9> as Rational>::F64 - exact).abs(), 1.8147765323828224e-07);
+/// assert_eq!((<Ln2<U10> as Rational>::F64 - exact).abs(), 8.322253808312752e-08);
+/// assert_eq!((<Ln2<U11> as Rational>::F64 - exact).abs(), 2.756188210994992e-08);
+/// assert_eq!((<Ln2<U12> as Rational>::F64 - exact).abs(), 1.3171083113050486e-08);
+/// assert_eq!((<Ln2<U13> as Rational>::F64 - exact).abs(), 2.6078847970012475e-09);
+/// assert_eq!((<Ln2<U14> as Rational>::F64 - exact).abs(), 6.789213635727265e-11);
+/// assert_eq!((<Ln2<U15> as Rational>::F64 - exact).abs(), 4.1802117323186394e-12);
+/// assert_eq!((<Ln2<U16> as Rational>::F64 - exact).abs(), 2.2110091535409993e-12);
+/// assert_eq!((<Ln2<U17> as Rational>::F64 - exact).abs(), 8.363310044501304e-13);
+/// assert_eq!((<Ln2<U18> as Rational>::F64 - exact).abs(), 2.1038726316646716e-13);
+/// assert_eq!((<Ln2<U19> as Rational>::F64 - exact).abs(), 4.829470157119431e-14);
+/// assert_eq!((<Ln2<U20> as Rational>::F64 - exact).abs(), 2.2426505097428162e-14);
+/// assert_eq!((<Ln2<U21> as Rational>::F64 - exact).abs(), 7.327471962526033e-15);
+/// assert_eq!((<Ln2<U22> as Rational>::F64 - exact).abs(), 3.552713678800501e-15);
+/// assert_eq!((<Ln2<U23> as Rational>::F64 - exact).abs(), 6.661338147750939e-16);
+/// assert_eq!((<Ln2<U24> as Rational>::F64 - exact).abs(), 0.0);
+/// ```
+pub type Ln2<I> = NthConvergent<Ln2Terms, I>;
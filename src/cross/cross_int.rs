@@ -1,12 +1,18 @@
 use std::{
     fmt::Binary,
-    ops::{Add, Deref, DerefMut, Div, Mul, Neg, Rem, Sub},
+    ops::{Add, BitAnd, BitOr, BitXor, Deref, DerefMut, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub},
+};
+use typenum::{
+    Abs, Cmp, Compare, Gcd, Integer, Logarithm2, Max, Min, NInt, NonZero, PInt, Pow, SquareRoot, ToInt, UInt,
+    Unsigned,
 };
-use typenum::{Abs, Cmp, Compare, Gcd, Integer, Max, Min, NInt, NonZero, PInt, Pow, ToInt, UInt, Unsigned};
 
 use crate::{
+    SignedSubtract,
+    IntegerBitAnd, IntegerBitOr, IntegerBitXor, IntegerNot, IntegerShl, IntegerShr,
+    CeilDiv, DivAndRem, EuclidDiv, EuclidRem, FloorDiv, FloorMod,
     FromBit, FromInteger, FromRational, FromUnsigned, GetZero, IntoBit, IntoInteger, IntoRational,
-    IntoUnsigned, IsZero, R,
+    IntoUnsigned, IsZero, Lcm, R,
 };
 
 /// Same as a [int](crate::int) but enables operations with right hand sides being other types ([uint](crate::uint), [rational](mod@crate::rational), ...)
@@ -15,6 +21,10 @@ use crate::{
 /// Here is the list of these traits:
 /// - [Div]
 /// - [Mul]
+/// - [Sub], through [SignedSubtract]
+/// - [BitAnd], [BitOr], [BitXor] and [Not], in two's complement
+/// - [Shl] and [Shr], with the shift amount converted with [IntoUnsigned]
+/// - [DivAndRem]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CrossInt<I> {
     i: I,
@@ -182,6 +192,15 @@ where
 impl_binary! {Gcd => Output {}}
 impl_unary! {GetZero => Output {}}
 
+impl<I> Logarithm2 for CrossInt<I>
+where
+    I: IntoUnsigned,
+    <I as IntoUnsigned>::Output: Logarithm2,
+    <<I as IntoUnsigned>::Output as Logarithm2>::Output: IntoInteger,
+{
+    type Output = CrossInt<Into<<<I as IntoUnsigned>::Output as Logarithm2>::Output>>;
+}
+
 impl<I> IsZero for CrossInt<I>
 where
     I: IsZero,
@@ -189,6 +208,75 @@ where
     type Output = <I as IsZero>::Output;
 }
 
+impl_binary! {Lcm => Output {}}
+
+// Bitwise operators, in two's complement.
+macro_rules! impl_bitwise {
+    ($Trait:ident, $fn:ident => $Integer:ident) => {
+        impl<I1, I2> $Trait<I2> for CrossInt<I1>
+        where
+            I2: IntoInteger,
+            I1: $Integer<Into<I2>>,
+            <I1 as $Integer<Into<I2>>>::Output: Default,
+        {
+            type Output = CrossInt<<I1 as $Integer<Into<I2>>>::Output>;
+
+            fn $fn(self, _rhs: I2) -> Self::Output {
+                CrossInt::default()
+            }
+        }
+    };
+}
+impl_bitwise! {BitAnd, bitand => IntegerBitAnd}
+impl_bitwise! {BitOr, bitor => IntegerBitOr}
+impl_bitwise! {BitXor, bitxor => IntegerBitXor}
+
+impl<I> Not for CrossInt<I>
+where
+    I: IntegerNot,
+    <I as IntegerNot>::Output: Default,
+{
+    type Output = CrossInt<<I as IntegerNot>::Output>;
+
+    fn not(self) -> Self::Output {
+        CrossInt::default()
+    }
+}
+
+/// Shifts are arithmetic, the shift amount is converted with [IntoUnsigned].
+macro_rules! impl_shift {
+    ($Trait:ident, $fn:ident => $Integer:ident) => {
+        impl<I, S> $Trait<S> for CrossInt<I>
+        where
+            S: IntoUnsigned,
+            I: $Integer<<S as IntoUnsigned>::Output>,
+            <I as $Integer<<S as IntoUnsigned>::Output>>::Output: Default,
+        {
+            type Output = CrossInt<<I as $Integer<<S as IntoUnsigned>::Output>>::Output>;
+
+            fn $fn(self, _rhs: S) -> Self::Output {
+                CrossInt::default()
+            }
+        }
+    };
+}
+impl_shift! {Shl, shl => IntegerShl}
+impl_shift! {Shr, shr => IntegerShr}
+
+impl_binary! {FloorDiv => Output {}}
+impl_binary! {FloorMod => Output {}}
+impl_binary! {CeilDiv => Output {}}
+impl_binary! {EuclidDiv => Output {}}
+impl_binary! {EuclidRem => Output {}}
+
+impl<I1, I2, Q, Rm> DivAndRem<I2> for CrossInt<I1>
+where
+    I2: IntoInteger,
+    I1: DivAndRem<Into<I2>, Output = (Q, Rm)>,
+{
+    type Output = (CrossInt<Q>, CrossInt<Rm>);
+}
+
 impl_binary! {Max => Output {
     fn max(self, _rhs: I2) -> Self::Output {
         CrossInt {i: self.i.max(into!(I2))}
@@ -269,11 +357,27 @@ impl_binary!{Rem => Output {
         CrossInt{i: self.i.rem(into!(I2))}
     }
 }}
-impl_binary!{Sub => Output {
+/// Uses [SignedSubtract], so that inner [Unsigned] numbers can go negative.
+impl<I1, I2> Sub<I2> for CrossInt<I1>
+where
+    I1: SignedSubtract<I2>,
+    <I1 as SignedSubtract<I2>>::Output: Default,
+{
+    type Output = CrossInt<<I1 as SignedSubtract<I2>>::Output>;
+
     fn sub(self, _rhs: I2) -> Self::Output {
-        CrossInt {i: self.i.sub(into!(I2))}
+        CrossInt::default()
     }
-}}
+}
+
+impl<I> SquareRoot for CrossInt<I>
+where
+    I: IntoUnsigned,
+    <I as IntoUnsigned>::Output: SquareRoot,
+    <<I as IntoUnsigned>::Output as SquareRoot>::Output: IntoInteger,
+{
+    type Output = CrossInt<Into<<<I as IntoUnsigned>::Output as SquareRoot>::Output>>;
+}
 
 impl<T, I> ToInt<T> for CrossInt<I>
 where I: ToInt<T>
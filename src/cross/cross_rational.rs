@@ -2,16 +2,36 @@ use std::{
     fmt::Binary,
     ops::{Add, Deref, DerefMut, Div, Mul, Neg, Rem, Sub},
 };
-use typenum::{Abs, Cmp, Compare, Gcd, Max, Min, Pow};
+use typenum::{Abs, Cmp, Compare, Gcd, Logarithm2, Max, Min, Pow, SquareRoot};
 
 use crate::{
-    FromBit, FromInteger, FromRational, FromUnsigned, GetZero, IntoBit, IntoInteger, IntoRational,
-    IntoUnsigned, IsZero, Simplify,
+    CeilDiv, DivAndRem, EuclidDiv, EuclidRem, FloorDiv, FloorMod,
+    ExactSquareRoot, FromBit, FromInteger, FromRational, FromUnsigned, GetZero, IntoBit, IntoInteger,
+    IntoRational, IntoUnsigned, IsZero, Lcm, LimitDenom, Simplify,
 };
 
 /// Same as a [rational](mod@crate::rational) but enables operations with right hand sides being other types 
 /// ([uint](crate::uint), [int](crate::int), ...)
-
+///
+/// The right hand side is converted with [IntoRational], so a rational right hand side is kept exact.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Sum<CrossRational<rational!(P1, U2)>, U1>, CrossRational<rational!(P3, U2)>);
+/// assert_type_eq!(Prod<CrossRational<rational!(P1, U2)>, N3>, CrossRational<rational!(N3, U2)>);
+/// assert_type_eq!(Quot<CrossRational<rational!(P1, U2)>, B1>, CrossRational<rational!(P1, U2)>);
+///
+/// assert_type_eq!(Sum<CrossRational<rational!(P1, U2)>, rational!(P1, U3)>, CrossRational<rational!(P5, U6)>);
+/// assert_type_eq!(Diff<CrossRational<rational!(P1, U2)>, rational!(P1, U3)>, CrossRational<rational!(P1, U6)>);
+/// assert_type_eq!(Quot<CrossRational<rational!(P1, U2)>, rational!(P1, U3)>, CrossRational<rational!(P3, U2)>);
+/// assert_type_eq!(Maximum<CrossRational<rational!(P1, U2)>, rational!(P2, U3)>, CrossRational<rational!(P2, U3)>);
+/// assert_type_eq!(Compare<CrossRational<rational!(P1, U2)>, rational!(P1, U3)>, Greater);
+///
+/// let sum = CrossRational::from(<rational!(P1, U2)>::new()) + <rational!(P1, U3)>::new();
+/// assert_eq!(sum.to_f64(), 5.0 / 6.0);
+/// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CrossRational<R> {
     r: R,
@@ -34,11 +54,11 @@ macro_rules! impl_binary {
     ($Trait:ident => $($Output:ident)* {$($content:tt)*}) => {
         impl<I1, I2> $Trait<I2> for CrossRational<I1>
         where
-            I2: $crate::IntoInteger,
-            I1: $Trait<<I2 as IntoInteger>::Output>,
+            I2: $crate::IntoRational,
+            I1: $Trait<<I2 as IntoRational>::Output>,
         {
             $(
-                type $Output = CrossRational<<I1 as $Trait<<I2 as IntoInteger>::Output>>::$Output>;
+                type $Output = CrossRational<<I1 as $Trait<<I2 as IntoRational>::Output>>::$Output>;
             )*
 
             $($content)*
@@ -47,18 +67,18 @@ macro_rules! impl_binary {
     };
 }
 
-type Into<R> = <R as IntoInteger>::Output;
+type Into<R> = <R as IntoRational>::Output;
 
 macro_rules! into {
-    ($T:path) => {
-        <$T as IntoInteger>::Output::default()
+    ($rhs:expr) => {
+        $rhs.into_rational()
     };
 }
 
 impl_unary! {Abs => Output {}}
 impl_binary! {Add => Output {
-    fn add(self, _rhs: I2) -> Self::Output {
-        CrossRational {r: self.r.add(into!(I2))}
+    fn add(self, rhs: I2) -> Self::Output {
+        CrossRational {r: self.r.add(into!(rhs))}
     }
 }}
 impl_unary! {Binary => {
@@ -69,22 +89,24 @@ impl_unary! {Binary => {
 
 impl<I1, I2> Cmp<I2> for CrossRational<I1>
 where
-    I2: IntoInteger,
+    I2: IntoRational,
     I1: Cmp<Into<I2>>,
 {
     type Output = Compare<I1, Into<I2>>;
 
-    fn compare<IM: typenum::private::InternalMarker>(&self, _: &I2) -> Self::Output {
-        self.r.compare::<IM>(&into!(I2))
+    fn compare<IM: typenum::private::InternalMarker>(&self, rhs: &I2) -> Self::Output {
+        self.r.compare::<IM>(&into!(rhs))
     }
 }
 
 impl_binary!{Div => Output {
-    fn div(self, _rhs: I2) -> Self::Output {
-        CrossRational{r: self.r.div(into!(I2))}
+    fn div(self, rhs: I2) -> Self::Output {
+        CrossRational{r: self.r.div(into!(rhs))}
     }
 }}
 
+impl_unary! {ExactSquareRoot => Output {}}
+
 impl<B> FromBit for CrossRational<B>
 where
     B: IntoBit,
@@ -132,6 +154,15 @@ where
 impl_binary! {Gcd => Output {}}
 impl_unary! {GetZero => Output {}}
 
+/// The integral result is converted back into a rational.
+impl<R> Logarithm2 for CrossRational<R>
+where
+    R: Logarithm2,
+    <R as Logarithm2>::Output: IntoRational,
+{
+    type Output = CrossRational<Into<<R as Logarithm2>::Output>>;
+}
+
 impl<R> IsZero for CrossRational<R>
 where
     R: IsZero,
@@ -139,20 +170,59 @@ where
     type Output = <R as IsZero>::Output;
 }
 
+impl<R, Max> LimitDenom<Max> for CrossRational<R>
+where
+    Max: IntoUnsigned,
+    R: LimitDenom<<Max as IntoUnsigned>::Output>,
+{
+    type Output = CrossRational<<R as LimitDenom<<Max as IntoUnsigned>::Output>>::Output>;
+}
+
+impl_binary! {Lcm => Output {}}
+
+/// Implements an operator whose integral result is converted back into a rational.
+macro_rules! impl_binary_integral {
+    ($Trait:ident) => {
+        impl<I1, I2> $Trait<I2> for CrossRational<I1>
+        where
+            I2: IntoRational,
+            I1: $Trait<Into<I2>>,
+            <I1 as $Trait<Into<I2>>>::Output: IntoRational,
+        {
+            type Output = CrossRational<Into<<I1 as $Trait<Into<I2>>>::Output>>;
+        }
+    };
+}
+
+impl_binary_integral! {FloorDiv}
+impl_binary! {FloorMod => Output {}}
+impl_binary_integral! {CeilDiv}
+impl_binary_integral! {EuclidDiv}
+impl_binary! {EuclidRem => Output {}}
+
+impl<I1, I2, Q, Rm> DivAndRem<I2> for CrossRational<I1>
+where
+    I2: IntoRational,
+    I1: DivAndRem<Into<I2>, Output = (Q, Rm)>,
+    Q: IntoRational,
+{
+    type Output = (CrossRational<Into<Q>>, CrossRational<Rm>);
+}
+
 impl_binary! {Max => Output {
-    fn max(self, _rhs: I2) -> Self::Output {
-        CrossRational {r: self.r.max(into!(I2))}
+    fn max(self, rhs: I2) -> Self::Output {
+        CrossRational {r: self.r.max(into!(rhs))}
     }
 }}
 impl_binary! {Min => Output {
-    fn min(self, _rhs: I2) -> Self::Output {
-        CrossRational {r: self.r.min(into!(I2))}
+    fn min(self, rhs: I2) -> Self::Output {
+        CrossRational {r: self.r.min(into!(rhs))}
     }
 }}
 
 impl_binary!{Mul => Output {
-    fn mul(self, _rhs: I2) -> Self::Output {
-        CrossRational{r: self.r.mul(into!(I2))}
+    fn mul(self, rhs: I2) -> Self::Output {
+        CrossRational{r: self.r.mul(into!(rhs))}
     }
 }}
 
@@ -162,18 +232,26 @@ impl_unary! {Neg => Output {
     }
 }}
 impl_binary!{Pow => Output {
-    fn powi(self, _exp: I2) -> Self::Output {
-        CrossRational{r: self.r.powi(into!(I2))}
+    fn powi(self, exp: I2) -> Self::Output {
+        CrossRational{r: self.r.powi(into!(exp))}
     }
 }}
 impl_binary!{Rem => Output {
-    fn rem(self, _rhs: I2) -> Self::Output {
-        CrossRational{r: self.r.rem(into!(I2))}
+    fn rem(self, rhs: I2) -> Self::Output {
+        CrossRational{r: self.r.rem(into!(rhs))}
     }
 }}
+/// The integral result is converted back into a rational.
+impl<R> SquareRoot for CrossRational<R>
+where
+    R: SquareRoot,
+    <R as SquareRoot>::Output: IntoRational,
+{
+    type Output = CrossRational<Into<<R as SquareRoot>::Output>>;
+}
 impl_binary!{Sub => Output {
-    fn sub(self, _rhs: I2) -> Self::Output {
-        CrossRational {r: self.r.sub(into!(I2))}
+    fn sub(self, rhs: I2) -> Self::Output {
+        CrossRational {r: self.r.sub(into!(rhs))}
     }
 }}
 
@@ -6,6 +6,11 @@ pub use typenum::*;
 pub mod rational;
 pub use rational::*;
 
+pub mod consts;
+
+pub mod modular;
+pub use modular::*;
+
 pub mod conversions;
 pub use conversions::*;
 
@@ -15,5 +20,8 @@ pub use operator_aliases_extended::*;
 pub mod type_operators_extended;
 pub use type_operators_extended::*;
 
+#[cfg(feature = "derive")]
+pub use extended_typenum_derive::TypeDisplay;
+
 pub mod cross;
 pub use cross::*;
\ No newline at end of file
@@ -0,0 +1,326 @@
+//! Type level modular arithmetic.
+//!
+//! A residue modulo `M` is represented by [Residue], holding an [Unsigned] value in `0..M`.
+//! Like [rational](mod@crate::rational) numbers, residues must always be reduced, which is why it is recommended to build them
+//! with the [IntoMod] trait or [AsMod] alias, from any [IntoInteger] value.
+//!
+//! Residues implement [Add], [Sub], [Mul], [Neg], [Pow] with an [Unsigned] exponent, and [Div] when the divisor is invertible.
+//! The results are always reduced.
+//!
+//! The type is not called `Mod` so that typenum's `Mod` alias of the [Rem] operator keeps working.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! type A = AsMod<U5, U7>;
+//! type B = AsMod<N3, U7>;
+//!
+//! assert_type_eq!(B, Residue<U4, U7>);
+//! assert_type_eq!(Sum<A, B>, Residue<U2, U7>);
+//! assert_type_eq!(Diff<B, A>, Residue<U6, U7>);
+//! assert_type_eq!(Prod<A, B>, Residue<U6, U7>);
+//! assert_type_eq!(Negate<A>, Residue<U2, U7>);
+//! assert_type_eq!(Exp<A, U6>, Residue<U1, U7>);
+//! assert_type_eq!(Quot<Residue<U1, U7>, A>, Residue<U3, U7>);
+//! assert_type_eq!(Prod<Quot<B, A>, A>, B);
+//!
+//! assert_eq!((A::new() + B::new()).value(), U2::new());
+//! // type Fail = Quot<Residue<U1, U6>, Residue<U2, U6>>; 2 is not invertible modulo 6.
+//!
+//! // typenum's `Mod` alias is still the remainder.
+//! assert_type_eq!(Mod<U7, U3>, U1);
+//! ```
+
+use std::ops::*;
+
+use super::*;
+
+/// A type level residue modulo `M`.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Residue<V: Unsigned, M: Unsigned + NonZero> {
+    pub(crate) val: V,
+    pub(crate) modulus: M,
+}
+impl<V: Unsigned, M: Unsigned + NonZero> Residue<V, M> {
+    /// Creates a new residue.
+    ///
+    /// **Warning:** `V` must be less than `M`!
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value of the residue, in `0..M`.
+    pub fn value(&self) -> V {
+        self.val
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> M {
+        self.modulus
+    }
+}
+
+/// Reduces an [Unsigned] into `0..M`.
+type Reduce<X, M> = <X as Rem<M>>::Output;
+
+/// Converts the implementor's type into a residue modulo `M`.
+///
+/// It is implemented for every [IntoInteger] type, negative values being reduced into `0..M` too.
+///
+/// More convinient syntax with the associated [AsMod] alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(AsMod<U10, U7>, Residue<U3, U7>);
+/// assert_type_eq!(AsMod<N10, U7>, Residue<U4, U7>);
+/// assert_type_eq!(AsMod<N14, U7>, Residue<U0, U7>);
+/// assert_type_eq!(AsMod<B1, U7>, Residue<U1, U7>);
+/// assert_type_eq!(AsMod<rational!(N3), U7>, Residue<U4, U7>);
+/// assert_type_eq!(AsMod<P5, U1>, Residue<U0, U1>);
+/// ```
+pub trait IntoMod<M> {
+    /// The output type of the conversion.
+    type Output;
+
+    /// Converts into a residue.
+    fn into_mod(self) -> Self::Output;
+}
+
+impl<T: IntoInteger, M: Unsigned + NonZero> IntoMod<M> for T
+where
+    AsInteger<T>: PrivateReduce<M>,
+{
+    type Output = Residue<<AsInteger<T> as PrivateReduce<M>>::Output, M>;
+
+    fn into_mod(self) -> Self::Output {
+        Residue::new()
+    }
+}
+
+/// Reduces an [Integer] into `0..M`.
+#[doc(hidden)]
+pub trait PrivateReduce<M> {
+    type Output: Unsigned;
+}
+impl<M: Unsigned + NonZero> PrivateReduce<M> for Z0 {
+    type Output = U0;
+}
+impl<U: Unsigned + NonZero, M: Unsigned + NonZero> PrivateReduce<M> for PInt<U>
+where
+    U: Rem<M>,
+    Reduce<U, M>: Unsigned,
+{
+    type Output = Reduce<U, M>;
+}
+/// `-u mod m = (m - u mod m) mod m`
+impl<U: Unsigned + NonZero, M: Unsigned + NonZero> PrivateReduce<M> for NInt<U>
+where
+    U: Rem<M>,
+    M: Sub<Reduce<U, M>>,
+    Diff<M, Reduce<U, M>>: Rem<M>,
+    Reduce<Diff<M, Reduce<U, M>>, M>: Unsigned,
+{
+    type Output = Reduce<Diff<M, Reduce<U, M>>, M>;
+}
+
+/// Computes the inverse of `Self` modulo `M`, with the extended Euclidean algorithm.
+///
+/// It is implemented for [Unsigned] numbers, and is not implemented if `Self` and `M` are not coprime.
+/// The inverse is in `0..M`.
+///
+/// More convinient syntax with the associated [ModInverse] type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ModInverse<U3, U7>, U5);
+/// assert_type_eq!(ModInverse<U10, U7>, U5);
+/// assert_type_eq!(ModInverse<U1, U2>, U1);
+/// assert_type_eq!(ModInverse<U0, U1>, U0);
+/// assert_eq!(<ModInverse<U17, Prod<U12, U260>> as Unsigned>::USIZE, 2753);
+/// // type Fail = ModInverse<U4, U6>; Not coprime.
+/// ```
+pub trait ModularInverse<M> {
+    /// The inverse of `Self`.
+    type Output;
+}
+
+impl<A: Unsigned, M: Unsigned + NonZero> ModularInverse<M> for A
+where
+    A: Rem<M>,
+    (Reduce<A, M>, M, P1, Z0): PrivateExtendedEuclid,
+    <(Reduce<A, M>, M, P1, Z0) as PrivateExtendedEuclid>::Output: PrivateReduce<M>,
+{
+    type Output = <<(Reduce<A, M>, M, P1, Z0) as PrivateExtendedEuclid>::Output as PrivateReduce<M>>::Output;
+}
+
+/// Extended Euclidean algorithm. The state is `(OldR, R, OldS, S)`, where `OldS` and `S` are the Bézout coefficients of `OldR` and `R`.
+///
+/// Only implemented if the gcd is one, the output being the Bézout coefficient.
+#[doc(hidden)]
+pub trait PrivateExtendedEuclid {
+    type Output;
+}
+impl<OldR, OldS, S> PrivateExtendedEuclid for (OldR, U0, OldS, S)
+where
+    OldR: Same<U1>,
+{
+    type Output = OldS;
+}
+impl<OldR, U: Unsigned, B: Bit, OldS, S> PrivateExtendedEuclid for (OldR, UInt<U, B>, OldS, S)
+where
+    OldR: Div<UInt<U, B>> + Rem<UInt<U, B>>,
+    Quot<OldR, UInt<U, B>>: IntoInteger,
+    AsInteger<Quot<OldR, UInt<U, B>>>: Mul<S>,
+    OldS: Sub<Prod<AsInteger<Quot<OldR, UInt<U, B>>>, S>>,
+    (UInt<U, B>, Reduce<OldR, UInt<U, B>>, S, Diff<OldS, Prod<AsInteger<Quot<OldR, UInt<U, B>>>, S>>): PrivateExtendedEuclid,
+{
+    type Output = <(UInt<U, B>, Reduce<OldR, UInt<U, B>>, S, Diff<OldS, Prod<AsInteger<Quot<OldR, UInt<U, B>>>, S>>) as PrivateExtendedEuclid>::Output;
+}
+
+/// Computes `Self` to the power `E` modulo `M`, by square and multiply.
+///
+/// It is implemented for [Unsigned] numbers and exponents, and the result is in `0..M`.
+/// As every intermediate result is reduced, big exponents are fine.
+///
+/// More convinient syntax with the associated [ModPow] type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ModPow<U3, U0, U7>, U1);
+/// assert_type_eq!(ModPow<U3, U4, U7>, U4);
+/// assert_type_eq!(ModPow<U5, U1, U1>, U0);
+/// assert_type_eq!(ModPow<U2, U1000, U1000>, U376);
+///
+/// // 17 generates the multiplicative group modulo the NTT prime 7681 = 15 * 2^9 + 1.
+/// type P = Sum<Prod<U15, U512>, U1>;
+/// assert_type_eq!(ModPow<U17, Diff<P, U1>, P>, U1);
+/// assert_eq!(<ModPow<U17, Prod<U15, U256>, P> as Unsigned>::USIZE, 7680);
+///
+/// // Primitive 512-th root of unity.
+/// type W = ModPow<U17, U15, P>;
+/// assert_eq!(<W as Unsigned>::USIZE, 7146);
+/// assert_type_eq!(ModPow<W, U512, P>, U1);
+/// assert_eq!(<ModPow<W, U256, P> as Unsigned>::USIZE, 7680);
+/// ```
+pub trait ModularPow<E, M> {
+    /// `Self` to the power `E` modulo `M`.
+    type Output;
+}
+
+impl<A: Unsigned, M: Unsigned + NonZero> ModularPow<U0, M> for A
+where
+    U1: Rem<M>,
+{
+    type Output = Reduce<U1, M>;
+}
+
+/// `a^(2e) = (a^e)^2`
+impl<A: Unsigned, U: Unsigned, M: Unsigned + NonZero> ModularPow<UInt<U, B0>, M> for A
+where
+    A: ModularPow<U, M>,
+    <A as ModularPow<U, M>>::Output: Mul,
+    Square<<A as ModularPow<U, M>>::Output>: Rem<M>,
+{
+    type Output = Reduce<Square<<A as ModularPow<U, M>>::Output>, M>;
+}
+
+/// `a^(2e + 1) = (a^e)^2 * a`
+impl<A: Unsigned, U: Unsigned, M: Unsigned + NonZero> ModularPow<UInt<U, B1>, M> for A
+where
+    A: ModularPow<U, M> + Rem<M>,
+    <A as ModularPow<U, M>>::Output: Mul,
+    Square<<A as ModularPow<U, M>>::Output>: Rem<M>,
+    Reduce<Square<<A as ModularPow<U, M>>::Output>, M>: Mul<Reduce<A, M>>,
+    Prod<Reduce<Square<<A as ModularPow<U, M>>::Output>, M>, Reduce<A, M>>: Rem<M>,
+{
+    type Output = Reduce<Prod<Reduce<Square<<A as ModularPow<U, M>>::Output>, M>, Reduce<A, M>>, M>;
+}
+
+// Add
+impl<Vl: Unsigned, Vr: Unsigned, M: Unsigned + NonZero> Add<Residue<Vr, M>> for Residue<Vl, M>
+where
+    Vl: Add<Vr>,
+    Sum<Vl, Vr>: Rem<M>,
+    Reduce<Sum<Vl, Vr>, M>: Unsigned,
+{
+    type Output = Residue<Reduce<Sum<Vl, Vr>, M>, M>;
+
+    fn add(self, _rhs: Residue<Vr, M>) -> Self::Output {
+        Residue::new()
+    }
+}
+
+// Neg
+impl<V: Unsigned, M: Unsigned + NonZero> Neg for Residue<V, M>
+where
+    M: Sub<V>,
+    Diff<M, V>: Rem<M>,
+    Reduce<Diff<M, V>, M>: Unsigned,
+{
+    type Output = Residue<Reduce<Diff<M, V>, M>, M>;
+
+    fn neg(self) -> Self::Output {
+        Residue::new()
+    }
+}
+
+// Sub
+impl<Vl: Unsigned, Vr: Unsigned, M: Unsigned + NonZero> Sub<Residue<Vr, M>> for Residue<Vl, M>
+where
+    Residue<Vr, M>: Neg,
+    Residue<Vl, M>: Add<Negate<Residue<Vr, M>>>,
+{
+    type Output = Sum<Residue<Vl, M>, Negate<Residue<Vr, M>>>;
+
+    fn sub(self, rhs: Residue<Vr, M>) -> Self::Output {
+        self.add(rhs.neg())
+    }
+}
+
+// Mul
+impl<Vl: Unsigned, Vr: Unsigned, M: Unsigned + NonZero> Mul<Residue<Vr, M>> for Residue<Vl, M>
+where
+    Vl: Mul<Vr>,
+    Prod<Vl, Vr>: Rem<M>,
+    Reduce<Prod<Vl, Vr>, M>: Unsigned,
+{
+    type Output = Residue<Reduce<Prod<Vl, Vr>, M>, M>;
+
+    fn mul(self, _rhs: Residue<Vr, M>) -> Self::Output {
+        Residue::new()
+    }
+}
+
+// Div
+impl<Vl: Unsigned, Vr: Unsigned, M: Unsigned + NonZero> Div<Residue<Vr, M>> for Residue<Vl, M>
+where
+    Vr: ModularInverse<M>,
+    ModInverse<Vr, M>: Unsigned,
+    Residue<Vl, M>: Mul<Residue<ModInverse<Vr, M>, M>>,
+{
+    type Output = Prod<Residue<Vl, M>, Residue<ModInverse<Vr, M>, M>>;
+
+    fn div(self, _rhs: Residue<Vr, M>) -> Self::Output {
+        self.mul(Residue::new())
+    }
+}
+
+// Pow
+impl<V: Unsigned, M: Unsigned + NonZero, E: Unsigned> Pow<E> for Residue<V, M>
+where
+    V: ModularPow<E, M>,
+    ModPow<V, E, M>: Unsigned,
+{
+    type Output = Residue<ModPow<V, E, M>, M>;
+
+    fn powi(self, _exp: E) -> Self::Output {
+        Residue::new()
+    }
+}
@@ -16,12 +16,350 @@ pub type AsInteger<A> = <A as IntoInteger>::Output;
 /// Converts a type into a [Rational] number.
 pub type AsRational<A> = <A as IntoRational>::Output;
 
+/// Converts a type into a residue modulo `M`.
+pub type AsMod<A, M> = <A as IntoMod<M>>::Output;
+
 /// Converts an [Integer] and [Unsigned] into a rational number.
 pub type ToRational<N, D> = <R<N, D> as Simplify>::Output;
 
 /// Simplifies a [Rational] number.
 pub type Simplified<R> = <R as Simplify>::Output;
 
+/// Closest rational number with a denominator at most `Max`.
+/// 
+/// Based on the [LimitDenom] trait.
+pub type LimitDenominator<A, Max> = <A as LimitDenom<Max>>::Output;
+
+/// Mediant of two rational numbers.
+/// 
+/// Based on the [GetMediant] trait.
+pub type Mediant<A, B> = <A as GetMediant<B>>::Output;
+
+/// Path to a rational number in the Stern–Brocot tree.
+/// 
+/// Based on the [SternBrocotPath] trait.
+pub type SBPath<A> = <A as SternBrocotPath>::Output;
+
+/// Parent of a rational number in the Stern–Brocot tree.
+/// 
+/// Based on the [SternBrocotParent] trait.
+pub type SBParent<A> = <A as SternBrocotParent>::Output;
+
+/// Left child of a rational number in the Stern–Brocot tree.
+/// 
+/// Based on the [SternBrocotChildren] trait.
+pub type SBLeftChild<A> = <A as SternBrocotChildren>::Left;
+
+/// Right child of a rational number in the Stern–Brocot tree.
+/// 
+/// Based on the [SternBrocotChildren] trait.
+pub type SBRightChild<A> = <A as SternBrocotChildren>::Right;
+
+/// Left neighbour of a rational number in the Farey sequence of order `Order`.
+/// 
+/// Based on the [FareyNeighbours] trait.
+pub type FareyLeft<A, Order> = <A as FareyNeighbours<Order>>::Left;
+
+/// Right neighbour of a rational number in the Farey sequence of order `Order`.
+/// 
+/// Based on the [FareyNeighbours] trait.
+pub type FareyRight<A, Order> = <A as FareyNeighbours<Order>>::Right;
+
+/// Exact square root of a rational number.
+/// 
+/// Based on the [ExactSquareRoot] trait.
+pub type ExactSqrt<A> = <A as ExactSquareRoot>::Output;
+
+/// Rational approximation of the `N`-th root of a rational number, with `Iterations` steps of Newton's method.
+/// 
+/// Based on the [RootApproximation] trait.
+pub type RootApprox<A, N, Iterations> = <A as RootApproximation<N, Iterations>>::Output;
+
+/// Same as [RootApprox], but the denominator is limited to `Cap` after each step.
+/// 
+/// Based on the [RootApproximation] trait.
+pub type RootApproxCapped<A, N, Iterations, Cap> = <A as RootApproximation<N, Iterations, Cap>>::Output;
+
+/// Rational approximation of the square root of a rational number, with `Iterations` steps of Newton's method.
+/// 
+/// Based on the [RootApproximation] trait.
+pub type SqrtApprox<A, Iterations> = <A as RootApproximation<U2, Iterations>>::Output;
+
+/// Same as [SqrtApprox], but the denominator is limited to `Cap` after each step.
+/// 
+/// Based on the [RootApproximation] trait.
+pub type SqrtApproxCapped<A, Iterations, Cap> = <A as RootApproximation<U2, Iterations, Cap>>::Output;
+
+/// Convergent of index `Index` of a continued fraction.
+/// 
+/// Based on the [Convergent] trait.
+pub type NthConvergent<Terms, Index> = <Terms as Convergent<Index>>::Output;
+
+/// `(PrePeriod, Period)` digits of the decimal expansion of the fractional part of a rational number.
+/// 
+/// Based on the [DecimalExpand] trait.
+pub type DecimalExpansion<A> = <A as DecimalExpand>::Output;
+
+/// Whether the decimal expansion of a rational number terminates.
+/// 
+/// Based on the [TerminatingDecimalTest] trait.
+pub type IsTerminatingDecimal<A> = <A as TerminatingDecimalTest>::Output;
+
+/// Least common multiple.
+/// 
+/// Based on the [Lcm] trait.
+pub type LeastCommonMultiple<A, B> = <A as Lcm<B>>::Output;
+
+/// Greatest common divisor of all the elements of an array.
+/// 
+/// Based on the [FoldGcd] trait.
+pub type FoldGreatestCommonDivisor<A> = <A as FoldGcd>::Output;
+
+/// Least common multiple of all the elements of an array.
+/// 
+/// Based on the [FoldLcm] trait.
+pub type FoldLeastCommonMultiple<A> = <A as FoldLcm>::Output;
+
+/// Checks if a number is prime.
+/// 
+/// Based on the [TestPrime] trait.
+pub type IsPrime<A> = <A as TestPrime>::Output;
+
+/// Prime factorization of a number, as a list of `(prime, exponent)` pairs.
+/// 
+/// Based on the [Factorization] trait.
+pub type Factorize<A> = <A as Factorization>::Output;
+
+/// Sorted list of the divisors of a number.
+/// 
+/// Based on the [GetDivisors] trait.
+pub type Divisors<A> = <A as GetDivisors>::Output;
+
+/// Euler's totient of a number.
+/// 
+/// Based on the [EulerTotient] trait.
+pub type Totient<A> = <A as EulerTotient>::Output;
+
+/// Product of the distinct prime factors of a number.
+/// 
+/// Based on the [GetRadical] trait.
+pub type Radical<A> = <A as GetRadical>::Output;
+
+/// Exponent of the prime `P` in the factorization of `X`.
+/// 
+/// Based on the [PAdicValuation] trait.
+pub type Valuation<P, X> = <X as PAdicValuation<P>>::Output;
+
+/// Factorial of a number.
+/// 
+/// Based on the [GetFactorial] trait.
+pub type Factorial<A> = <A as GetFactorial>::Output;
+
+/// Falling factorial `X * (X - 1) * ... * (X - K + 1)`.
+/// 
+/// Based on the [GetFallingFactorial] trait.
+pub type FallingFactorial<X, K> = <X as GetFallingFactorial<K>>::Output;
+
+/// Rising factorial `X * (X + 1) * ... * (X + K - 1)`.
+/// 
+/// Based on the [GetRisingFactorial] trait.
+pub type RisingFactorial<X, K> = <X as GetRisingFactorial<K>>::Output;
+
+/// Binomial coefficient `X` choose `K`.
+/// 
+/// Based on the [GetBinomial] trait.
+pub type Binomial<X, K> = <X as GetBinomial<K>>::Output;
+
+/// Inverse of `A` modulo `M`.
+/// 
+/// Based on the [ModularInverse] trait.
+pub type ModInverse<A, M> = <A as ModularInverse<M>>::Output;
+
+/// `B` to the power `E` modulo `M`.
+/// 
+/// Based on the [ModularPow] trait.
+pub type ModPow<B, E, M> = <B as ModularPow<E, M>>::Output;
+
+/// Quotient of `A` by `B`, rounded toward negative infinity.
+/// 
+/// Based on the [FloorDiv] trait.
+pub type DivFloor<A, B> = <A as FloorDiv<B>>::Output;
+
+/// Remainder of the division of `A` by `B` rounded toward negative infinity.
+/// 
+/// Based on the [FloorMod] trait.
+pub type ModFloor<A, B> = <A as FloorMod<B>>::Output;
+
+/// Quotient of `A` by `B`, rounded toward positive infinity.
+/// 
+/// Based on the [CeilDiv] trait.
+pub type DivCeil<A, B> = <A as CeilDiv<B>>::Output;
+
+/// Quotient of the Euclidean division of `A` by `B`.
+/// 
+/// Based on the [EuclidDiv] trait.
+pub type DivEuclid<A, B> = <A as EuclidDiv<B>>::Output;
+
+/// Remainder of the Euclidean division of `A` by `B`.
+/// 
+/// Based on the [EuclidRem] trait.
+pub type RemEuclid<A, B> = <A as EuclidRem<B>>::Output;
+
+/// Truncated quotient and remainder of `A` by `B`, as a `(Quotient, Remainder)` pair.
+/// 
+/// Based on the [DivAndRem] trait.
+pub type DivRem<A, B> = <A as DivAndRem<B>>::Output;
+
+/// Two's-complement bitwise and of two [Integer]s.
+/// 
+/// Based on the [IntegerBitAnd] trait.
+pub type IntAnd<A, B> = <A as IntegerBitAnd<B>>::Output;
+
+/// Two's-complement bitwise or of two [Integer]s.
+/// 
+/// Based on the [IntegerBitOr] trait.
+pub type IntOr<A, B> = <A as IntegerBitOr<B>>::Output;
+
+/// Two's-complement bitwise exclusive or of two [Integer]s.
+/// 
+/// Based on the [IntegerBitXor] trait.
+pub type IntXor<A, B> = <A as IntegerBitXor<B>>::Output;
+
+/// Two's-complement bitwise not of an [Integer].
+/// 
+/// Based on the [IntegerNot] trait.
+pub type IntNot<A> = <A as IntegerNot>::Output;
+
+/// Left shift of an [Integer].
+/// 
+/// Based on the [IntegerShl] trait.
+pub type IntShl<A, S> = <A as IntegerShl<S>>::Output;
+
+/// Arithmetic right shift of an [Integer].
+/// 
+/// Based on the [IntegerShr] trait.
+pub type IntShr<A, S> = <A as IntegerShr<S>>::Output;
+
+/// `Bits`-bit two's-complement encoding of an [Integer].
+/// 
+/// Based on the [TwosComplementEncode] trait.
+pub type ToTwosComplement<I, Bits> = <I as TwosComplementEncode<Bits>>::Output;
+
+/// [Integer] encoded in the `Bits` lowest bits of an [Unsigned] in two's complement.
+/// 
+/// Based on the [TwosComplementDecode] trait.
+pub type FromTwosComplement<U, Bits> = <U as TwosComplementDecode<Bits>>::Output;
+
+/// `W` lowest bits of an [Unsigned].
+/// 
+/// Based on the [TruncateBits] trait.
+pub type Truncate<U, W> = <U as TruncateBits<W>>::Output;
+
+/// Sum of two [Unsigned] numbers on `W` bits, wrapping around.
+/// 
+/// Based on the [WrapAdd] trait.
+pub type WrappingAdd<A, B, W> = <A as WrapAdd<B, W>>::Output;
+
+/// Difference of two [Unsigned] numbers on `W` bits, wrapping around.
+/// 
+/// Based on the [WrapSub] trait.
+pub type WrappingSub<A, B, W> = <A as WrapSub<B, W>>::Output;
+
+/// Product of two [Unsigned] numbers on `W` bits, wrapping around.
+/// 
+/// Based on the [WrapMul] trait.
+pub type WrappingMul<A, B, W> = <A as WrapMul<B, W>>::Output;
+
+/// Wrapped sum of two [Unsigned] numbers on `W` bits and its overflow flag.
+/// 
+/// Based on the [OverflowAdd] trait.
+pub type OverflowingAdd<A, B, W> = <A as OverflowAdd<B, W>>::Output;
+
+/// Sum of two [Unsigned] numbers on `W` bits, saturating at `2^W - 1`.
+/// 
+/// Based on the [SaturateAdd] trait.
+pub type SaturatingAdd<A, B, W> = <A as SaturateAdd<B, W>>::Output;
+
+/// Difference of two numbers as an [Integer], which can be negative.
+/// 
+/// Based on the [SignedSubtract] trait.
+pub type SignedSub<A, B> = <A as SignedSubtract<B>>::Output;
+
+/// Difference of two [Unsigned] numbers, clamped to [U0].
+/// 
+/// Based on the [SaturateSub] trait.
+pub type SaturatingSub<A, B> = <A as SaturateSub<B>>::Output;
+
+/// Difference of two [Unsigned] numbers and its validity flag.
+/// 
+/// Based on the [CheckSub] trait.
+pub type CheckedSub<A, B> = <A as CheckSub<B>>::Output;
+
+/// Number of ones in the binary representation of an [Unsigned].
+/// 
+/// Based on the [PopCount] trait.
+pub type CountOnes<U> = <U as PopCount>::Output;
+
+/// Number of zeros after the lowest one in the binary representation of an [Unsigned].
+/// 
+/// Based on the [TrailingZeroCount] trait.
+pub type TrailingZeros<U> = <U as TrailingZeroCount>::Output;
+
+/// Number of bits of an [Unsigned], without leading zeros.
+/// 
+/// Based on typenum's [Len] trait.
+pub type BitLength<U> = <U as Len>::Output;
+
+/// Whether an [Unsigned] is a power of two.
+/// 
+/// Based on the [PowerOfTwoTest] trait.
+pub type IsPowerOfTwo<U> = <U as PowerOfTwoTest>::Output;
+
+/// Smallest power of two greater than or equal to an [Unsigned].
+/// 
+/// Based on the [CeilPowerOfTwo] trait.
+pub type NextPowerOfTwo<U> = <U as CeilPowerOfTwo>::Output;
+
+/// Largest power of two less than or equal to an [Unsigned].
+/// 
+/// Based on the [FloorPowerOfTwo] trait.
+pub type PrevPowerOfTwo<U> = <U as FloorPowerOfTwo>::Output;
+
+/// `W` lowest bits of an [Unsigned] in reverse order.
+/// 
+/// Based on the [BitReversal] trait.
+pub type ReverseBits<U, W> = <U as BitReversal<W>>::Output;
+
+/// Bit of index `I` of an [Unsigned], as [True] or [False].
+/// 
+/// Based on typenum's [GetBit](typenum::uint::GetBit) trait.
+pub type GetBit<U, I> = <U as typenum::uint::GetBit<I>>::Output;
+
+/// [Unsigned] with its bit of index `I` set to `B`.
+/// 
+/// Based on typenum's [SetBit](typenum::uint::SetBit) trait.
+pub type SetBit<U, I, B> = <U as typenum::uint::SetBit<I, B>>::Output;
+
+/// List of the bits of an [Unsigned], most significant first by default.
+/// 
+/// Based on the [IntoBits] trait.
+pub type ToBits<U, Order = MsbFirst> = <U as IntoBits<Order>>::Output;
+
+/// [Unsigned] from its list of bits, most significant first by default.
+/// 
+/// Based on the [FromBitList] trait.
+pub type FromBits<List, Order = MsbFirst> = <List as FromBitList<Order>>::Output;
+
+/// List of the digits of an [Unsigned] in base `Radix`, most significant first by default.
+/// 
+/// Based on the [IntoDigits] trait.
+pub type ToDigits<U, Radix, Order = MsbFirst> = <U as IntoDigits<Radix, Order>>::Output;
+
+/// [Unsigned] from its list of digits in base `Radix`, most significant first by default.
+/// 
+/// Based on the [FromDigitList] trait.
+pub type FromDigits<List, Radix, Order = MsbFirst> = <List as FromDigitList<Radix, Order>>::Output;
+
 /// If then else operator.
 /// 
 /// Based on the [TypeIf] trait.
@@ -46,6 +46,21 @@ impl<N: Integer, D: Unsigned + NonZero> R<N, D> {
 mod simplify;
 pub use simplify::*;
 
+mod limit_denominator;
+pub use limit_denominator::*;
+
+mod stern_brocot;
+pub use stern_brocot::*;
+
+mod roots;
+pub use roots::*;
+
+mod continued_fraction;
+pub use continued_fraction::*;
+
+mod decimal_expansion;
+pub use decimal_expansion::*;
+
 
 /// Macro to create a rational number type.
 /// 
@@ -0,0 +1,70 @@
+//! Rational numbers from their [continued fraction](https://en.wikipedia.org/wiki/Continued_fraction) expansion.
+//!
+//! A continued fraction `a0 + 1/(a1 + 1/(a2 + ...))` is represented by the [TArr] of its [Unsigned] terms `tarr![a0, a1, a2, ...]`.
+
+use std::ops::*;
+
+use super::*;
+
+/// Computes the `Index`-th convergent of a continued fraction, starting at zero.
+///
+/// The convergent of index `I` is the value of the continued fraction made of the first `I + 1` terms.
+/// It is not implemented if there are not enough terms.
+///
+/// The convergents are computed with the usual recurrence, and are always simplified.
+///
+/// More convinient syntax with the associated [NthConvergent](crate::operator_aliases_extended::NthConvergent) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// type Terms = tarr![U3, U7, U15, U1];
+///
+/// assert_type_eq!(NthConvergent<Terms, U0>, rational!(P3));
+/// assert_type_eq!(NthConvergent<Terms, U1>, rational!(P22, U7));
+/// assert_type_eq!(NthConvergent<Terms, U3>, rational!(P355, U113));
+/// assert_type_eq!(NthConvergent<tarr![U0, U2], U1>, rational!(P1, U2));
+/// assert_type_eq!(NthConvergent<tarr![U0, U2], U0>, rational!(Z0));
+/// // type Fail = NthConvergent<Terms, U4>; Not enough terms.
+/// ```
+pub trait Convergent<Index> {
+    /// The convergent.
+    type Output;
+}
+
+impl<A, Rest, Index> Convergent<Index> for TArr<A, Rest>
+where
+    (TArr<A, Rest>, U1, U0, U0, U1): PrivateConvergent<Index>,
+{
+    type Output = <(TArr<A, Rest>, U1, U0, U0, U1) as PrivateConvergent<Index>>::Output;
+}
+
+/// Recurrence `p(k) = a(k) * p(k - 1) + p(k - 2)`, same for `q`.
+///
+/// The state is `(Terms, P1, Q1, P0, Q0)` where `P1/Q1` is the previous convergent and `P0/Q0` the one before.
+#[doc(hidden)]
+pub trait PrivateConvergent<Index> {
+    type Output;
+}
+impl<A, Rest, P1, Q1, P0, Q0> PrivateConvergent<U0> for (TArr<A, Rest>, P1, Q1, P0, Q0)
+where
+    A: Mul<P1> + Mul<Q1>,
+    Prod<A, P1>: Add<P0>,
+    Prod<A, Q1>: Add<Q0>,
+    Sum<Prod<A, P1>, P0>: IntoInteger,
+    Sum<Prod<A, Q1>, Q0>: Unsigned + NonZero,
+{
+    type Output = R<AsInteger<Sum<Prod<A, P1>, P0>>, Sum<Prod<A, Q1>, Q0>>;
+}
+impl<A, Rest, P1, Q1, P0, Q0, U: Unsigned, B: Bit> PrivateConvergent<UInt<U, B>> for (TArr<A, Rest>, P1, Q1, P0, Q0)
+where
+    A: Mul<P1> + Mul<Q1>,
+    Prod<A, P1>: Add<P0>,
+    Prod<A, Q1>: Add<Q0>,
+    UInt<U, B>: Sub<B1>,
+    (Rest, Sum<Prod<A, P1>, P0>, Sum<Prod<A, Q1>, Q0>, P1, Q1): PrivateConvergent<Sub1<UInt<U, B>>>,
+{
+    type Output =
+        <(Rest, Sum<Prod<A, P1>, P0>, Sum<Prod<A, Q1>, Q0>, P1, Q1) as PrivateConvergent<Sub1<UInt<U, B>>>>::Output;
+}
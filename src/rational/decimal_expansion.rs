@@ -0,0 +1,181 @@
+//! Exact decimal expansion of rational numbers.
+//!
+//! The decimal expansion of a rational number is eventually periodic: `1/6 = 0.1666...` is written `0.1(6)`,
+//! with the pre-period `1` and the period `6`. The expansion terminates, that is the period is empty,
+//! exactly when the denominator is of the form `2^a * 5^b`.
+//!
+//! The expansion can also be formatted with [TypeFormat::repeating](crate::TypeFormat::repeating).
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_type_eq!(DecimalExpansion<rational!(P1, U6)>, (tarr![U1], tarr![U6]));
+//! assert_type_eq!(DecimalExpansion<rational!(P1, U7)>, (tarr![], tarr![U1, U4, U2, U8, U5, U7]));
+//! assert_type_eq!(IsTerminatingDecimal<rational!(P1, U6)>, False);
+//! assert_type_eq!(IsTerminatingDecimal<rational!(P3, U40)>, True);
+//!
+//! let repeating = TypeFormat::new().repeating();
+//! assert_eq!(DisplayType::<rational!(P1, U6)>::with(repeating).to_string(), "0.1(6)");
+//! assert_eq!(DisplayType::<rational!(P1, U7)>::with(repeating).to_string(), "0.(142857)");
+//! ```
+
+use std::ops::*;
+
+use super::*;
+
+/// Computes the decimal expansion of the fractional part of the absolute value of a rational number.
+///
+/// The output is the `(PrePeriod, Period)` pair of [TArr] lists of [Unsigned] digits.
+/// The integer part can be obtained with the usual division operators.
+///
+/// More convinient syntax with the associated [DecimalExpansion](crate::operator_aliases_extended::DecimalExpansion) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(DecimalExpansion<rational!(P1, U4)>, (tarr![U2, U5], tarr![]));
+/// assert_type_eq!(DecimalExpansion<rational!(P1, U3)>, (tarr![], tarr![U3]));
+/// assert_type_eq!(DecimalExpansion<rational!(N7, U6)>, (tarr![U1], tarr![U6]));
+/// assert_type_eq!(DecimalExpansion<rational!(P7, U12)>, (tarr![U5, U8], tarr![U3]));
+/// assert_type_eq!(DecimalExpansion<rational!(P1, U11)>, (tarr![], tarr![U0, U9]));
+/// assert_type_eq!(DecimalExpansion<rational!(P3)>, (tarr![], tarr![]));
+/// ```
+pub trait DecimalExpand {
+    /// The `(PrePeriod, Period)` pair.
+    type Output;
+}
+
+/// The pre-period has `max(a, b)` digits where `D = 2^a * 5^b * m` with `m` coprime to 10.
+/// The period starts from the remainder reached after the pre-period.
+impl<N: Integer, D: Unsigned + NonZero> DecimalExpand for R<N, D>
+where
+    N: Abs,
+    AbsVal<N>: IntoUnsigned,
+    AsUnsigned<AbsVal<N>>: Rem<D>,
+    D: PrivateStrip<U2, UTerm> + PrivateStrip<U5, UTerm>,
+    <D as PrivateStrip<U2, UTerm>>::Count: Max<<D as PrivateStrip<U5, UTerm>>::Count>,
+    <AsUnsigned<AbsVal<N>> as Rem<D>>::Output: PrivatePrePeriod<
+        D,
+        Maximum<<D as PrivateStrip<U2, UTerm>>::Count, <D as PrivateStrip<U5, UTerm>>::Count>,
+    >,
+    PrivatePeriodStart<N, D>: PrivatePeriod<D>,
+{
+    type Output = (
+        <<AsUnsigned<AbsVal<N>> as Rem<D>>::Output as PrivatePrePeriod<
+            D,
+            Maximum<<D as PrivateStrip<U2, UTerm>>::Count, <D as PrivateStrip<U5, UTerm>>::Count>,
+        >>::Digits,
+        <PrivatePeriodStart<N, D> as PrivatePeriod<D>>::Digits,
+    );
+}
+
+/// Remainder of the long division after the pre-period.
+#[doc(hidden)]
+pub type PrivatePeriodStart<N, D> = <<AsUnsigned<AbsVal<N>> as Rem<D>>::Output as PrivatePrePeriod<
+    D,
+    Maximum<<D as PrivateStrip<U2, UTerm>>::Count, <D as PrivateStrip<U5, UTerm>>::Count>,
+>>::Remainder;
+
+/// Tells whether the decimal expansion of a rational number terminates, giving [True] or [False].
+///
+/// It is the case when the denominator is of the form `2^a * 5^b`.
+///
+/// More convinient syntax with the associated [IsTerminatingDecimal](crate::operator_aliases_extended::IsTerminatingDecimal) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IsTerminatingDecimal<rational!(P5)>, True);
+/// assert_type_eq!(IsTerminatingDecimal<rational!(N1, U8)>, True);
+/// assert_type_eq!(IsTerminatingDecimal<rational!(P7, U250)>, True);
+/// assert_type_eq!(IsTerminatingDecimal<rational!(P1, U3)>, False);
+/// assert_type_eq!(IsTerminatingDecimal<rational!(P1, U30)>, False);
+/// ```
+pub trait TerminatingDecimalTest {
+    /// [True] or [False].
+    type Output;
+}
+
+impl<N: Integer, D: Unsigned + NonZero> TerminatingDecimalTest for R<N, D>
+where
+    D: PrivateStrip<U2, UTerm>,
+    <D as PrivateStrip<U2, UTerm>>::Rest: PrivateStrip<U5, UTerm>,
+    <<D as PrivateStrip<U2, UTerm>>::Rest as PrivateStrip<U5, UTerm>>::Rest: IsEqual<U1>,
+{
+    type Output = <<<D as PrivateStrip<U2, UTerm>>::Rest as PrivateStrip<U5, UTerm>>::Rest as IsEqual<U1>>::Output;
+}
+
+/// `K` steps of long division by `D` from the remainder `Self`.
+#[doc(hidden)]
+pub trait PrivatePrePeriod<D, K> {
+    type Digits;
+    type Remainder;
+}
+impl<Rm, D> PrivatePrePeriod<D, UTerm> for Rm {
+    type Digits = ATerm;
+    type Remainder = Rm;
+}
+impl<Rm, D, U: Unsigned, B: Bit> PrivatePrePeriod<D, UInt<U, B>> for Rm
+where
+    Rm: Mul<U10>,
+    Prod<Rm, U10>: Div<D> + Rem<D>,
+    UInt<U, B>: Sub<B1>,
+    <Prod<Rm, U10> as Rem<D>>::Output: PrivatePrePeriod<D, Sub1<UInt<U, B>>>,
+{
+    type Digits = TArr<
+        Quot<Prod<Rm, U10>, D>,
+        <<Prod<Rm, U10> as Rem<D>>::Output as PrivatePrePeriod<D, Sub1<UInt<U, B>>>>::Digits,
+    >;
+    type Remainder = <<Prod<Rm, U10> as Rem<D>>::Output as PrivatePrePeriod<D, Sub1<UInt<U, B>>>>::Remainder;
+}
+
+/// Long division by `D` from the remainder `Self`, until it comes back. Empty for a zero remainder.
+#[doc(hidden)]
+pub trait PrivatePeriod<D> {
+    type Digits;
+}
+impl<D> PrivatePeriod<D> for UTerm {
+    type Digits = ATerm;
+}
+impl<U: Unsigned, B: Bit, D> PrivatePeriod<D> for UInt<U, B>
+where
+    UInt<U, B>: PrivatePeriodFrom<D, UInt<U, B>>,
+{
+    type Digits = <UInt<U, B> as PrivatePeriodFrom<D, UInt<U, B>>>::Digits;
+}
+
+#[doc(hidden)]
+pub trait PrivatePeriodFrom<D, Start> {
+    type Digits;
+}
+impl<Rm, D, Start> PrivatePeriodFrom<D, Start> for Rm
+where
+    Rm: Mul<U10>,
+    Prod<Rm, U10>: Div<D> + Rem<D>,
+    <Prod<Rm, U10> as Rem<D>>::Output: IsEqual<Start>,
+    <Prod<Rm, U10> as Rem<D>>::Output:
+        PrivatePeriodStep<D, Start, <<Prod<Rm, U10> as Rem<D>>::Output as IsEqual<Start>>::Output>,
+{
+    type Digits = TArr<
+        Quot<Prod<Rm, U10>, D>,
+        <<Prod<Rm, U10> as Rem<D>>::Output as PrivatePeriodStep<
+            D,
+            Start,
+            <<Prod<Rm, U10> as Rem<D>>::Output as IsEqual<Start>>::Output,
+        >>::Digits,
+    >;
+}
+
+#[doc(hidden)]
+pub trait PrivatePeriodStep<D, Start, Done> {
+    type Digits;
+}
+impl<Rm, D, Start> PrivatePeriodStep<D, Start, True> for Rm {
+    type Digits = ATerm;
+}
+impl<Rm: PrivatePeriodFrom<D, Start>, D, Start> PrivatePeriodStep<D, Start, False> for Rm {
+    type Digits = <Rm as PrivatePeriodFrom<D, Start>>::Digits;
+}
@@ -0,0 +1,176 @@
+//! The [LimitDenom] trait finds the closest rational number whose denominator does not exceed a given bound.
+//!
+//! The algorithm is the same as Python's `Fraction.limit_denominator`:
+//! the continued fraction expansion of the number is walked until the next convergent's denominator is too big,
+//! then the best of the last convergent and the last semiconvergent is selected.
+
+use std::ops::*;
+
+use super::operations::PrivateMin;
+use super::*;
+
+/// Finds the closest rational number whose denominator is at most `Max`.
+///
+/// If the denominator is already small enough, the number is returned unchanged.
+/// Otherwise, when two candidates are at the same distance, the last convergent of the continued fraction is chosen, as Python does.
+/// Negative numbers are handled through their absolute value, so the result is symmetric around zero.
+///
+/// The output is always simplified.
+///
+/// More convinient syntax with the associated [LimitDenominator](crate::operator_aliases_extended::LimitDenominator) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(LimitDenominator<rational!(P355, U113), U10>, rational!(P22, U7));
+/// assert_type_eq!(LimitDenominator<rational!(P355, U113), U100>, rational!(P311, U99));
+/// assert_type_eq!(LimitDenominator<rational!(N355, U113), U10>, rational!(N22, U7));
+/// assert_type_eq!(LimitDenominator<rational!(P1000, U257), U255>, rational!(P607, U156));
+///
+/// // Already small enough.
+/// assert_type_eq!(LimitDenominator<rational!(P3, U4), U4>, rational!(P3, U4));
+///
+/// // Ties go to the convergent.
+/// assert_type_eq!(LimitDenominator<rational!(P1, U4), U2>, rational!(Z0));
+/// assert_type_eq!(LimitDenominator<rational!(P3, U4), U2>, rational!(P1));
+///
+/// // Also works on cross types, the bound being converted with IntoUnsigned.
+/// assert_type_eq!(LimitDenominator<CrossRational<rational!(P355, U113)>, P10>, CrossRational<rational!(P22, U7)>);
+/// ```
+pub trait LimitDenom<Max> {
+    /// The closest rational with a denominator at most `Max`.
+    type Output;
+}
+
+impl<N: Integer, D: Unsigned + NonZero, Max: Unsigned + NonZero> LimitDenom<Max> for R<N, D>
+where
+    D: Cmp<Max>,
+    R<N, D>: PrivateLimitDenom<Max, Compare<D, Max>>,
+{
+    type Output = <R<N, D> as PrivateLimitDenom<Max, Compare<D, Max>>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateLimitDenom<Max, Cmp> {
+    type Output;
+}
+impl<N: Integer, D: Unsigned + NonZero, Max> PrivateLimitDenom<Max, Less> for R<N, D> {
+    type Output = R<N, D>;
+}
+impl<N: Integer, D: Unsigned + NonZero, Max> PrivateLimitDenom<Max, Equal> for R<N, D> {
+    type Output = R<N, D>;
+}
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero, Max> PrivateLimitDenom<Max, Greater> for R<PInt<N>, D>
+where
+    (R<PInt<N>, D>, U0, U1, U1, U0, N, D): PrivateLimitDenLoop<Max>,
+{
+    type Output = <(R<PInt<N>, D>, U0, U1, U1, U0, N, D) as PrivateLimitDenLoop<Max>>::Output;
+}
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero, Max> PrivateLimitDenom<Max, Greater> for R<NInt<N>, D>
+where
+    R<PInt<N>, D>: PrivateLimitDenom<Max, Greater>,
+    <R<PInt<N>, D> as PrivateLimitDenom<Max, Greater>>::Output: Neg,
+{
+    type Output = Negate<<R<PInt<N>, D> as PrivateLimitDenom<Max, Greater>>::Output>;
+}
+
+/// One step of the continued fraction expansion.
+///
+/// The state is `(X, P0, Q0, P1, Q1, N, D)` where `X` is the number to approximate,
+/// `P0/Q0` and `P1/Q1` are the last two convergents, and `N/D` is the remaining part of the expansion.
+#[doc(hidden)]
+pub trait PrivateLimitDenLoop<Max> {
+    type Output;
+}
+impl<X, P0, Q0, P1, Q1, N, D, Max> PrivateLimitDenLoop<Max> for (X, P0, Q0, P1, Q1, N, D)
+where
+    N: Div<D>,
+    Q1: Mul<Quot<N, D>>,
+    Q0: Add<Prod<Q1, Quot<N, D>>>,
+    Sum<Q0, Prod<Q1, Quot<N, D>>>: Cmp<Max>,
+    (X, P0, Q0, P1, Q1, N, D): PrivateLimitDenStep<Max, Compare<Sum<Q0, Prod<Q1, Quot<N, D>>>, Max>>,
+{
+    type Output = <(X, P0, Q0, P1, Q1, N, D) as PrivateLimitDenStep<
+        Max,
+        Compare<Sum<Q0, Prod<Q1, Quot<N, D>>>, Max>,
+    >>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateLimitDenStep<Max, Cmp> {
+    type Output;
+}
+
+/// Next convergent is still in bounds, keep going.
+#[doc(hidden)]
+pub type PrivateLimitDenNext<X, P0, Q0, P1, Q1, N, D> = (
+    X,
+    P1,
+    Q1,
+    Sum<P0, Prod<P1, Quot<N, D>>>,
+    Sum<Q0, Prod<Q1, Quot<N, D>>>,
+    D,
+    Diff<N, Prod<D, Quot<N, D>>>,
+);
+
+impl<X, P0, Q0, P1, Q1, N, D, Max> PrivateLimitDenStep<Max, Less> for (X, P0, Q0, P1, Q1, N, D)
+where
+    (X, P0, Q0, P1, Q1, N, D): PrivateLimitDenStep<Max, Equal>,
+{
+    type Output = <(X, P0, Q0, P1, Q1, N, D) as PrivateLimitDenStep<Max, Equal>>::Output;
+}
+impl<X, P0, Q0, P1, Q1, N, D, Max> PrivateLimitDenStep<Max, Equal> for (X, P0, Q0, P1, Q1, N, D)
+where
+    N: Div<D>,
+    P1: Mul<Quot<N, D>>,
+    Q1: Mul<Quot<N, D>>,
+    D: Mul<Quot<N, D>>,
+    P0: Add<Prod<P1, Quot<N, D>>>,
+    Q0: Add<Prod<Q1, Quot<N, D>>>,
+    N: Sub<Prod<D, Quot<N, D>>>,
+    PrivateLimitDenNext<X, P0, Q0, P1, Q1, N, D>: PrivateLimitDenLoop<Max>,
+{
+    type Output = <PrivateLimitDenNext<X, P0, Q0, P1, Q1, N, D> as PrivateLimitDenLoop<Max>>::Output;
+}
+
+/// Semiconvergent `(P0 + K*P1)/(Q0 + K*Q1)` with the biggest `K` keeping the denominator in bounds.
+#[doc(hidden)]
+pub type PrivateLimitDenBound<P0, Q0, P1, Q1, Max> = ToRational<
+    AsInteger<Sum<P0, Prod<P1, Quot<Diff<Max, Q0>, Q1>>>>,
+    Sum<Q0, Prod<Q1, Quot<Diff<Max, Q0>, Q1>>>,
+>;
+
+impl<X, P0, Q0, P1, Q1, N, D, Max> PrivateLimitDenStep<Max, Greater> for (X, P0, Q0, P1, Q1, N, D)
+where
+    Max: Sub<Q0>,
+    Diff<Max, Q0>: Div<Q1>,
+    P1: Mul<Quot<Diff<Max, Q0>, Q1>> + IntoInteger,
+    Q1: Mul<Quot<Diff<Max, Q0>, Q1>> + Unsigned + NonZero,
+    P0: Add<Prod<P1, Quot<Diff<Max, Q0>, Q1>>>,
+    Q0: Add<Prod<Q1, Quot<Diff<Max, Q0>, Q1>>>,
+    Sum<P0, Prod<P1, Quot<Diff<Max, Q0>, Q1>>>: IntoInteger,
+    Sum<Q0, Prod<Q1, Quot<Diff<Max, Q0>, Q1>>>: Unsigned + NonZero,
+    R<AsInteger<Sum<P0, Prod<P1, Quot<Diff<Max, Q0>, Q1>>>>, Sum<Q0, Prod<Q1, Quot<Diff<Max, Q0>, Q1>>>>: Simplify,
+    R<AsInteger<P1>, Q1>: Simplify,
+    ToRational<AsInteger<P1>, Q1>: Sub<X>,
+    PrivateLimitDenBound<P0, Q0, P1, Q1, Max>: Sub<X>,
+    Diff<ToRational<AsInteger<P1>, Q1>, X>: Abs,
+    Diff<PrivateLimitDenBound<P0, Q0, P1, Q1, Max>, X>: Abs,
+    AbsVal<Diff<ToRational<AsInteger<P1>, Q1>, X>>: Cmp<AbsVal<Diff<PrivateLimitDenBound<P0, Q0, P1, Q1, Max>, X>>>,
+    ToRational<AsInteger<P1>, Q1>: PrivateMin<
+        PrivateLimitDenBound<P0, Q0, P1, Q1, Max>,
+        Compare<
+            AbsVal<Diff<ToRational<AsInteger<P1>, Q1>, X>>,
+            AbsVal<Diff<PrivateLimitDenBound<P0, Q0, P1, Q1, Max>, X>>,
+        >,
+    >,
+{
+    type Output = <ToRational<AsInteger<P1>, Q1> as PrivateMin<
+        PrivateLimitDenBound<P0, Q0, P1, Q1, Max>,
+        Compare<
+            AbsVal<Diff<ToRational<AsInteger<P1>, Q1>, X>>,
+            AbsVal<Diff<PrivateLimitDenBound<P0, Q0, P1, Q1, Max>, X>>,
+        >,
+    >>::Output;
+}
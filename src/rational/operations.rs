@@ -27,6 +27,17 @@ impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> C
     }
 }
 
+// Gcd
+/// `gcd(a/b, c/d) = gcd(a, c)/lcm(b, d)`, which is already simplified.
+impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> Gcd<R<Nr, Dr>> for R<Nl, Dl> where
+    Nl: Gcd<Nr>,
+    Dl: Lcm<Dr>,
+    Gcf<Nl, Nr>: Integer,
+    <Dl as Lcm<Dr>>::Output: Unsigned + NonZero
+{
+    type Output = R<Gcf<Nl, Nr>, <Dl as Lcm<Dr>>::Output>;
+}
+
 // Max
 #[doc(hidden)]
 pub trait PrivateMax<Rhs, Cmp> {
@@ -162,6 +173,48 @@ R<NInt<D>, N>: Pow<R<PInt<U>, U1>>
     }
 }
 
+// SquareRoot
+impl<D: Unsigned + NonZero> SquareRoot for R<Z0, D> {
+    type Output = Z0;
+}
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> SquareRoot for R<PInt<N>, D> where
+N: Div<D>,
+Quot<N, D>: SquareRoot,
+Sqrt<Quot<N, D>>: IntoInteger
+{
+    type Output = AsInteger<Sqrt<Quot<N, D>>>;
+}
+
+// Logarithm2
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> Logarithm2 for R<PInt<N>, D> where
+N: Logarithm2,
+D: Logarithm2,
+N: Shl<Log2<D>>,
+D: Shl<Log2<N>>,
+Shleft<N, Log2<D>>: Cmp<Shleft<D, Log2<N>>>,
+Log2<N>: IntoInteger,
+Log2<D>: IntoInteger,
+AsInteger<Log2<N>>: Sub<AsInteger<Log2<D>>>,
+Diff<AsInteger<Log2<N>>, AsInteger<Log2<D>>>: PrivateLog2Correction<Compare<Shleft<N, Log2<D>>, Shleft<D, Log2<N>>>>
+{
+    type Output = <Diff<AsInteger<Log2<N>>, AsInteger<Log2<D>>> as PrivateLog2Correction<Compare<Shleft<N, Log2<D>>, Shleft<D, Log2<N>>>>>::Output;
+}
+
+/// `log2(N) - log2(D)` is either the result or one too many, depending on how `N * 2^log2(D)` and `D * 2^log2(N)` compare.
+#[doc(hidden)]
+pub trait PrivateLog2Correction<Cmp> {
+    type Output;
+}
+impl<K> PrivateLog2Correction<Greater> for K {
+    type Output = K;
+}
+impl<K> PrivateLog2Correction<Equal> for K {
+    type Output = K;
+}
+impl<K: Sub<P1>> PrivateLog2Correction<Less> for K {
+    type Output = Diff<K, P1>;
+}
+
 // Add
 impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> Add<R<Nr, Dr>> for R<Nl, Dl> where
     Dl: IntoInteger,
@@ -0,0 +1,184 @@
+//! Roots of rational numbers.
+//!
+//! Rationals implement typenum's [SquareRoot] and [Logarithm2] operators, which give the floor of the result as an [Integer].
+//! When the square root is rational, [ExactSquareRoot] gives it exactly.
+//! Otherwise, [RootApproximation] gives a rational approximation using Newton's method.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_type_eq!(Sqrt<rational!(P7, U2)>, P1);
+//! assert_type_eq!(Sqrt<rational!(P9, U4)>, P1);
+//! assert_type_eq!(Sqrt<rational!(P1, U4)>, Z0);
+//! assert_type_eq!(Sqrt<rational!(P100, U3)>, P5);
+//! assert_type_eq!(Sqrt<rational!(Z0)>, Z0);
+//!
+//! assert_type_eq!(Log2<rational!(P8)>, P3);
+//! assert_type_eq!(Log2<rational!(P5, U2)>, P1);
+//! assert_type_eq!(Log2<rational!(P1)>, Z0);
+//! assert_type_eq!(Log2<rational!(P3, U4)>, N1);
+//! assert_type_eq!(Log2<rational!(P1, U4)>, N2);
+//! assert_type_eq!(Log2<rational!(P1, U3)>, N2);
+//!
+//! // Also available on the cross types.
+//! assert_type_eq!(Sqrt<CrossInt<P10>>, CrossInt<P3>);
+//! assert_type_eq!(Log2<CrossInt<U10>>, CrossInt<P3>);
+//! assert_type_eq!(Sqrt<CrossRational<rational!(P9, U4)>>, CrossRational<rational!(P1)>);
+//! assert_type_eq!(Log2<CrossRational<rational!(P1, U3)>>, CrossRational<rational!(N2)>);
+//! ```
+
+use std::ops::*;
+
+use super::*;
+
+/// Square root of a rational number whose numerator and denominator are both perfect squares.
+///
+/// It is not implemented if the square root is not rational.
+///
+/// More convinient syntax with the associated [ExactSqrt](crate::operator_aliases_extended::ExactSqrt) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ExactSqrt<rational!(P9, U4)>, rational!(P3, U2));
+/// assert_type_eq!(ExactSqrt<rational!(P16)>, rational!(P4));
+/// assert_type_eq!(ExactSqrt<rational!(Z0)>, rational!(Z0));
+/// assert_type_eq!(ExactSqrt<CrossRational<rational!(P1, U100)>>, CrossRational<rational!(P1, U10)>);
+/// // type Fail = ExactSqrt<rational!(P2)>; Not a perfect square.
+/// ```
+pub trait ExactSquareRoot {
+    /// The square root of `Self`.
+    type Output;
+}
+
+impl ExactSquareRoot for R<Z0, U1> {
+    type Output = R<Z0, U1>;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> ExactSquareRoot for R<PInt<N>, D>
+where
+    N: SquareRoot,
+    D: SquareRoot,
+    Sqrt<N>: Mul + Unsigned + NonZero,
+    Sqrt<D>: Mul + Unsigned + NonZero,
+    Square<Sqrt<N>>: Same<N>,
+    Square<Sqrt<D>>: Same<D>,
+{
+    type Output = R<PInt<Sqrt<N>>, Sqrt<D>>;
+}
+
+/// Rational approximation of the `N`-th root of a rational number with `Iterations` steps of Newton's method.
+///
+/// Each step computes `((N - 1) * X + A / X^(N - 1)) / N`, starting from the power of two `2^ceil((log2(A) + 1) / N)`,
+/// which is above the root. Without a cap, the approximations stay above the root and decrease towards it.
+///
+/// The size of the numerator and denominator roughly doubles at each step.
+/// To keep the types bounded, a `Cap` can be given: after each step, the approximation is replaced by the closest rational
+/// whose denominator is at most `Cap` (see [LimitDenom]). A `Cap` of [U0] means no cap.
+/// Big approximations may require raising the crate's `recursion_limit`.
+///
+/// It is implemented for non-negative rational numbers.
+///
+/// More convinient syntax with the associated [RootApprox](crate::operator_aliases_extended::RootApprox),
+/// [RootApproxCapped](crate::operator_aliases_extended::RootApproxCapped), [SqrtApprox](crate::operator_aliases_extended::SqrtApprox)
+/// and [SqrtApproxCapped](crate::operator_aliases_extended::SqrtApproxCapped) type operator aliases.
+///
+/// Example:
+/// ```
+/// #![recursion_limit = "256"]
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(SqrtApprox<rational!(P2), U0>, rational!(P2));
+/// assert_type_eq!(SqrtApprox<rational!(P2), U1>, rational!(P3, U2));
+/// assert_type_eq!(SqrtApprox<rational!(P2), U2>, rational!(P17, U12));
+/// assert_type_eq!(SqrtApprox<rational!(P2), U3>, rational!(P577, U408));
+/// assert_type_eq!(SqrtApprox<rational!(P1, U2), U3>, rational!(P577, U816));
+/// assert_type_eq!(SqrtApprox<rational!(Z0), U3>, rational!(Z0));
+///
+/// assert_type_eq!(SqrtApproxCapped<rational!(P2), U5, U255>, rational!(P239, U169));
+/// assert_eq!(<SqrtApproxCapped<rational!(P2), U6, U1000> as Rational>::F64, 1393.0 / 985.0);
+///
+/// assert_eq!(<RootApprox<rational!(P2), U3, U3> as Rational>::F64, 125116.0 / 99225.0);
+///
+/// // Approximations can be derived from other constants.
+/// type Sqrt2 = SqrtApproxCapped<rational!(P2), U6, U1000>;
+/// type Sqrt8 = Prod<Sqrt2, rational!(P2)>;
+/// assert_eq!(<Sqrt8 as Rational>::F64, 2786.0 / 985.0);
+/// ```
+pub trait RootApproximation<N, Iterations, Cap = U0> {
+    /// The approximation of the root.
+    type Output;
+}
+
+impl<N, Iterations, Cap> RootApproximation<N, Iterations, Cap> for R<Z0, U1> {
+    type Output = R<Z0, U1>;
+}
+
+impl<Nu: Unsigned + NonZero, D: Unsigned + NonZero, N: Unsigned + NonZero, Iterations, Cap> RootApproximation<N, Iterations, Cap> for R<PInt<Nu>, D>
+where
+    R<PInt<Nu>, D>: Logarithm2,
+    Log2<R<PInt<Nu>, D>>: Add<P1>,
+    Sum<Log2<R<PInt<Nu>, D>>, P1>: Max<Z0>,
+    Maximum<Sum<Log2<R<PInt<Nu>, D>>, P1>, Z0>: IntoUnsigned,
+    N: Sub<B1>,
+    AsUnsigned<Maximum<Sum<Log2<R<PInt<Nu>, D>>, P1>, Z0>>: Add<Sub1<N>>,
+    Sum<AsUnsigned<Maximum<Sum<Log2<R<PInt<Nu>, D>>, P1>, Z0>>, Sub1<N>>: Div<N>,
+    U2: Pow<Quot<Sum<AsUnsigned<Maximum<Sum<Log2<R<PInt<Nu>, D>>, P1>, Z0>>, Sub1<N>>, N>>,
+    Exp<U2, Quot<Sum<AsUnsigned<Maximum<Sum<Log2<R<PInt<Nu>, D>>, P1>, Z0>>, Sub1<N>>, N>>: IntoRational,
+    (R<PInt<Nu>, D>, N, Cap, PrivateRootStart<R<PInt<Nu>, D>, N>): PrivateNewton<Iterations>,
+{
+    type Output = <(R<PInt<Nu>, D>, N, Cap, PrivateRootStart<R<PInt<Nu>, D>, N>) as PrivateNewton<Iterations>>::Output;
+}
+
+/// Starting point of Newton's method: `2^ceil(max(log2(A) + 1, 0) / N)`.
+#[doc(hidden)]
+pub type PrivateRootStart<A, N> =
+    AsRational<Exp<U2, Quot<Sum<AsUnsigned<Maximum<Sum<Log2<A>, P1>, Z0>>, Sub1<N>>, N>>>;
+
+/// One step of Newton's method for `X^N = A`.
+#[doc(hidden)]
+pub type PrivateNewtonStep<A, N, X> =
+    Quot<Sum<Prod<AsRational<Sub1<N>>, X>, Quot<A, Exp<X, AsRational<Sub1<N>>>>>, AsRational<N>>;
+
+/// Newton iterations. The state is `(A, N, Cap, X)` where `X` is the current approximation.
+#[doc(hidden)]
+pub trait PrivateNewton<Iterations> {
+    type Output;
+}
+impl<A, N, Cap, X> PrivateNewton<U0> for (A, N, Cap, X) {
+    type Output = X;
+}
+impl<A, N, Cap, X, U: Unsigned, B: Bit> PrivateNewton<UInt<U, B>> for (A, N, Cap, X)
+where
+    N: Sub<B1> + IntoRational,
+    Sub1<N>: IntoRational,
+    AsRational<Sub1<N>>: Mul<X>,
+    X: Pow<AsRational<Sub1<N>>>,
+    A: Div<Exp<X, AsRational<Sub1<N>>>>,
+    Prod<AsRational<Sub1<N>>, X>: Add<Quot<A, Exp<X, AsRational<Sub1<N>>>>>,
+    Sum<Prod<AsRational<Sub1<N>>, X>, Quot<A, Exp<X, AsRational<Sub1<N>>>>>: Div<AsRational<N>>,
+    PrivateNewtonStep<A, N, X>: PrivateCap<Cap>,
+    UInt<U, B>: Sub<B1>,
+    (A, N, Cap, <PrivateNewtonStep<A, N, X> as PrivateCap<Cap>>::Output): PrivateNewton<Sub1<UInt<U, B>>>,
+{
+    type Output = <(A, N, Cap, <PrivateNewtonStep<A, N, X> as PrivateCap<Cap>>::Output) as PrivateNewton<
+        Sub1<UInt<U, B>>,
+    >>::Output;
+}
+
+/// Applies the denominator cap, if any.
+#[doc(hidden)]
+pub trait PrivateCap<Cap> {
+    type Output;
+}
+impl<X> PrivateCap<U0> for X {
+    type Output = X;
+}
+impl<X, U: Unsigned, B: Bit> PrivateCap<UInt<U, B>> for X
+where
+    X: LimitDenom<UInt<U, B>>,
+{
+    type Output = LimitDenominator<X, UInt<U, B>>;
+}
@@ -0,0 +1,434 @@
+//! Navigation in the [Stern–Brocot tree](https://en.wikipedia.org/wiki/Stern%E2%80%93Brocot_tree) and in the Farey sequences.
+//!
+//! Every positive rational number appears exactly once in the Stern–Brocot tree, whose root is `1/1`.
+//! Each node is the [mediant](GetMediant) of its closest ancestors on the left and on the right,
+//! starting with the fictitious bounds `0/1` and `1/0`.
+//!
+//! Paths in the tree are encoded as [TArr] of bits, from the root to the node: [B0] for left and [B1] for right.
+
+use std::ops::*;
+
+use super::*;
+
+/// Computes the mediant of two rational numbers: `(a + c)/(b + d)` for `a/b` and `c/d`.
+///
+/// The output is simplified.
+///
+/// More convinient syntax with the associated [Mediant](crate::operator_aliases_extended::Mediant) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Mediant<rational!(P1, U2), rational!(P2, U3)>, rational!(P3, U5));
+/// assert_type_eq!(Mediant<rational!(N1, U3), rational!(P1, U3)>, rational!(Z0));
+/// assert_type_eq!(Mediant<rational!(P1, U3), rational!(P3, U5)>, rational!(P1, U2));
+/// ```
+pub trait GetMediant<Rhs> {
+    /// The mediant of `Self` and `Rhs`.
+    type Output;
+}
+
+impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> GetMediant<R<Nr, Dr>> for R<Nl, Dl>
+where
+    Nl: Add<Nr>,
+    Dl: Add<Dr>,
+    Sum<Nl, Nr>: Integer,
+    Sum<Dl, Dr>: Unsigned + NonZero,
+    R<Sum<Nl, Nr>, Sum<Dl, Dr>>: Simplify,
+{
+    type Output = rational!(Sum<Nl, Nr>, Sum<Dl, Dr>);
+}
+
+/// Path from the root of the Stern–Brocot tree to a positive rational number.
+///
+/// The output is a [TArr] of bits, [B0] meaning left and [B1] meaning right. The path of the root `1/1` is empty.
+///
+/// More convinient syntax with the associated [SBPath](crate::operator_aliases_extended::SBPath) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(SBPath<rational!(P1)>, tarr![]);
+/// assert_type_eq!(SBPath<rational!(P3, U5)>, tarr![B0, B1, B0]);
+/// assert_type_eq!(SBPath<rational!(P7, U2)>, tarr![B1, B1, B1, B0]);
+/// ```
+pub trait SternBrocotPath {
+    /// The path to `Self`.
+    type Output;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> SternBrocotPath for R<PInt<N>, D>
+where
+    (N, D, D, U0, U1, U1, U0): PrivateSBDescend,
+{
+    type Output = <(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Path;
+}
+
+/// Parent of a positive rational number in the Stern–Brocot tree.
+///
+/// It is not implemented for the root `1/1`.
+///
+/// More convinient syntax with the associated [SBParent](crate::operator_aliases_extended::SBParent) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(SBParent<rational!(P3, U5)>, rational!(P2, U3));
+/// assert_type_eq!(SBParent<rational!(P1, U2)>, rational!(P1));
+/// assert_type_eq!(SBParent<rational!(P7, U2)>, rational!(P4));
+/// // type Fail = SBParent<rational!(P1)>; The root has no parent.
+/// ```
+pub trait SternBrocotParent {
+    /// The parent of `Self`.
+    type Output;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> SternBrocotParent for R<PInt<N>, D>
+where
+    (N, D, D, U0, U1, U1, U0): PrivateSBDescend,
+    PrivateSBBounds<(N, D, D, U0, U1, U1, U0)>: PrivateSBParent,
+{
+    type Output = <PrivateSBBounds<(N, D, D, U0, U1, U1, U0)> as PrivateSBParent>::Output;
+}
+
+/// Children of a positive rational number in the Stern–Brocot tree.
+///
+/// More convinient syntax with the associated [SBLeftChild](crate::operator_aliases_extended::SBLeftChild)
+/// and [SBRightChild](crate::operator_aliases_extended::SBRightChild) type operator aliases.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(SBLeftChild<rational!(P1)>, rational!(P1, U2));
+/// assert_type_eq!(SBRightChild<rational!(P1)>, rational!(P2));
+/// assert_type_eq!(SBLeftChild<rational!(P3, U5)>, rational!(P4, U7));
+/// assert_type_eq!(SBRightChild<rational!(P3, U5)>, rational!(P5, U8));
+/// assert_type_eq!(SBParent<SBLeftChild<rational!(P3, U5)>>, rational!(P3, U5));
+/// ```
+pub trait SternBrocotChildren {
+    /// The left child of `Self`.
+    type Left;
+
+    /// The right child of `Self`.
+    type Right;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> SternBrocotChildren for R<PInt<N>, D>
+where
+    (N, D, D, U0, U1, U1, U0): PrivateSBDescend,
+    <(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Lp: Add<N>,
+    <(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Lq: Add<D>,
+    N: Add<<(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Rp>,
+    D: Add<<(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Rq>,
+    Sum<<(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Lp, N>: Unsigned + NonZero,
+    Sum<<(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Lq, D>: Unsigned + NonZero,
+    Sum<N, <(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Rp>: Unsigned + NonZero,
+    Sum<D, <(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Rq>: Unsigned + NonZero,
+{
+    type Left = R<
+        PInt<Sum<<(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Lp, N>>,
+        Sum<<(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Lq, D>,
+    >;
+    type Right = R<
+        PInt<Sum<N, <(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Rp>>,
+        Sum<D, <(N, D, D, U0, U1, U1, U0) as PrivateSBDescend>::Rq>,
+    >;
+}
+
+/// Neighbours of a rational number in the Farey sequence of order `Order`.
+///
+/// They are the closest rational numbers strictly below and strictly above `Self` whose denominators are at most `Order`.
+/// `Self` doesn't need to be part of the sequence, and the sequence is extended beyond `[0, 1]`.
+///
+/// More convinient syntax with the associated [FareyLeft](crate::operator_aliases_extended::FareyLeft)
+/// and [FareyRight](crate::operator_aliases_extended::FareyRight) type operator aliases.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(FareyLeft<rational!(P1, U3), U7>, rational!(P2, U7));
+/// assert_type_eq!(FareyRight<rational!(P1, U3), U7>, rational!(P2, U5));
+///
+/// // 5/8 is not in the sequence of order 3.
+/// assert_type_eq!(FareyLeft<rational!(P5, U8), U3>, rational!(P1, U2));
+/// assert_type_eq!(FareyRight<rational!(P5, U8), U3>, rational!(P2, U3));
+///
+/// assert_type_eq!(FareyLeft<rational!(P1), U3>, rational!(P2, U3));
+/// assert_type_eq!(FareyRight<rational!(P1), U3>, rational!(P4, U3));
+/// assert_type_eq!(FareyLeft<rational!(Z0), U3>, rational!(N1, U3));
+/// assert_type_eq!(FareyRight<rational!(N1, U3), U7>, rational!(N2, U7));
+/// ```
+pub trait FareyNeighbours<Order> {
+    /// The closest rational below `Self`.
+    type Left;
+
+    /// The closest rational above `Self`.
+    type Right;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero, Order: Unsigned + NonZero> FareyNeighbours<Order> for R<PInt<N>, D>
+where
+    (N, D, Order, U0, U1, U1, U0): PrivateSBDescend,
+    (N, D, Order, U0, U1, U1, U0): PrivateFarey<<(N, D, Order, U0, U1, U1, U0) as PrivateSBDescend>::Found>,
+{
+    type Left = <(N, D, Order, U0, U1, U1, U0) as PrivateFarey<
+        <(N, D, Order, U0, U1, U1, U0) as PrivateSBDescend>::Found,
+    >>::Left;
+    type Right = <(N, D, Order, U0, U1, U1, U0) as PrivateFarey<
+        <(N, D, Order, U0, U1, U1, U0) as PrivateSBDescend>::Found,
+    >>::Right;
+}
+
+impl<Order: Unsigned + NonZero> FareyNeighbours<Order> for R<Z0, U1> {
+    type Left = R<NInt<U1>, Order>;
+    type Right = R<PInt<U1>, Order>;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero, Order: Unsigned + NonZero> FareyNeighbours<Order> for R<NInt<N>, D>
+where
+    R<PInt<N>, D>: FareyNeighbours<Order>,
+    <R<PInt<N>, D> as FareyNeighbours<Order>>::Left: Neg,
+    <R<PInt<N>, D> as FareyNeighbours<Order>>::Right: Neg,
+{
+    type Left = Negate<<R<PInt<N>, D> as FareyNeighbours<Order>>::Right>;
+    type Right = Negate<<R<PInt<N>, D> as FareyNeighbours<Order>>::Left>;
+}
+
+/// Descent in the Stern–Brocot tree.
+///
+/// The state is `(N, D, Limit, Lp, Lq, Rp, Rq)` where `N/D` is the target,
+/// `Lp/Lq` and `Rp/Rq` are the current bounds, and `Limit` is the biggest allowed denominator.
+/// The descent stops when the target is found or when the next mediant's denominator exceeds `Limit`.
+#[doc(hidden)]
+pub trait PrivateSBDescend {
+    type Path;
+    type Found;
+    type Lp;
+    type Lq;
+    type Rp;
+    type Rq;
+}
+
+#[doc(hidden)]
+pub type PrivateSBBounds<S> = (
+    <S as PrivateSBDescend>::Lp,
+    <S as PrivateSBDescend>::Lq,
+    <S as PrivateSBDescend>::Rp,
+    <S as PrivateSBDescend>::Rq,
+);
+
+impl<N, D, Limit, Lp, Lq, Rp, Rq> PrivateSBDescend for (N, D, Limit, Lp, Lq, Rp, Rq)
+where
+    Lq: Add<Rq>,
+    Sum<Lq, Rq>: Cmp<Limit>,
+    (N, D, Limit, Lp, Lq, Rp, Rq): PrivateSBLimit<Compare<Sum<Lq, Rq>, Limit>>,
+{
+    type Path = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Compare<Sum<Lq, Rq>, Limit>>>::Path;
+    type Found = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Compare<Sum<Lq, Rq>, Limit>>>::Found;
+    type Lp = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Compare<Sum<Lq, Rq>, Limit>>>::Lp;
+    type Lq = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Compare<Sum<Lq, Rq>, Limit>>>::Lq;
+    type Rp = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Compare<Sum<Lq, Rq>, Limit>>>::Rp;
+    type Rq = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Compare<Sum<Lq, Rq>, Limit>>>::Rq;
+}
+
+#[doc(hidden)]
+pub trait PrivateSBLimit<Cmp> {
+    type Path;
+    type Found;
+    type Lp;
+    type Lq;
+    type Rp;
+    type Rq;
+}
+impl<N, D, Limit, Lp, Lq, Rp, Rq> PrivateSBLimit<Greater> for (N, D, Limit, Lp, Lq, Rp, Rq) {
+    type Path = ATerm;
+    type Found = False;
+    type Lp = Lp;
+    type Lq = Lq;
+    type Rp = Rp;
+    type Rq = Rq;
+}
+impl<N, D, Limit, Lp, Lq, Rp, Rq> PrivateSBLimit<Less> for (N, D, Limit, Lp, Lq, Rp, Rq)
+where
+    (N, D, Limit, Lp, Lq, Rp, Rq): PrivateSBLimit<Equal>,
+{
+    type Path = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Equal>>::Path;
+    type Found = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Equal>>::Found;
+    type Lp = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Equal>>::Lp;
+    type Lq = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Equal>>::Lq;
+    type Rp = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Equal>>::Rp;
+    type Rq = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBLimit<Equal>>::Rq;
+}
+impl<N, D, Limit, Lp, Lq, Rp, Rq> PrivateSBLimit<Equal> for (N, D, Limit, Lp, Lq, Rp, Rq)
+where
+    Lp: Add<Rp>,
+    Lq: Add<Rq>,
+    N: Mul<Sum<Lq, Rq>>,
+    Sum<Lp, Rp>: Mul<D>,
+    Prod<N, Sum<Lq, Rq>>: Cmp<Prod<Sum<Lp, Rp>, D>>,
+    (N, D, Limit, Lp, Lq, Rp, Rq): PrivateSBMove<Compare<Prod<N, Sum<Lq, Rq>>, Prod<Sum<Lp, Rp>, D>>>,
+{
+    type Path = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBMove<
+        Compare<Prod<N, Sum<Lq, Rq>>, Prod<Sum<Lp, Rp>, D>>,
+    >>::Path;
+    type Found = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBMove<
+        Compare<Prod<N, Sum<Lq, Rq>>, Prod<Sum<Lp, Rp>, D>>,
+    >>::Found;
+    type Lp = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBMove<
+        Compare<Prod<N, Sum<Lq, Rq>>, Prod<Sum<Lp, Rp>, D>>,
+    >>::Lp;
+    type Lq = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBMove<
+        Compare<Prod<N, Sum<Lq, Rq>>, Prod<Sum<Lp, Rp>, D>>,
+    >>::Lq;
+    type Rp = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBMove<
+        Compare<Prod<N, Sum<Lq, Rq>>, Prod<Sum<Lp, Rp>, D>>,
+    >>::Rp;
+    type Rq = <(N, D, Limit, Lp, Lq, Rp, Rq) as PrivateSBMove<
+        Compare<Prod<N, Sum<Lq, Rq>>, Prod<Sum<Lp, Rp>, D>>,
+    >>::Rq;
+}
+
+#[doc(hidden)]
+pub trait PrivateSBMove<Cmp> {
+    type Path;
+    type Found;
+    type Lp;
+    type Lq;
+    type Rp;
+    type Rq;
+}
+impl<N, D, Limit, Lp, Lq, Rp, Rq> PrivateSBMove<Equal> for (N, D, Limit, Lp, Lq, Rp, Rq) {
+    type Path = ATerm;
+    type Found = True;
+    type Lp = Lp;
+    type Lq = Lq;
+    type Rp = Rp;
+    type Rq = Rq;
+}
+impl<N, D, Limit, Lp, Lq, Rp, Rq> PrivateSBMove<Less> for (N, D, Limit, Lp, Lq, Rp, Rq)
+where
+    Lp: Add<Rp>,
+    Lq: Add<Rq>,
+    (N, D, Limit, Lp, Lq, Sum<Lp, Rp>, Sum<Lq, Rq>): PrivateSBDescend,
+{
+    type Path = TArr<B0, <(N, D, Limit, Lp, Lq, Sum<Lp, Rp>, Sum<Lq, Rq>) as PrivateSBDescend>::Path>;
+    type Found = <(N, D, Limit, Lp, Lq, Sum<Lp, Rp>, Sum<Lq, Rq>) as PrivateSBDescend>::Found;
+    type Lp = <(N, D, Limit, Lp, Lq, Sum<Lp, Rp>, Sum<Lq, Rq>) as PrivateSBDescend>::Lp;
+    type Lq = <(N, D, Limit, Lp, Lq, Sum<Lp, Rp>, Sum<Lq, Rq>) as PrivateSBDescend>::Lq;
+    type Rp = <(N, D, Limit, Lp, Lq, Sum<Lp, Rp>, Sum<Lq, Rq>) as PrivateSBDescend>::Rp;
+    type Rq = <(N, D, Limit, Lp, Lq, Sum<Lp, Rp>, Sum<Lq, Rq>) as PrivateSBDescend>::Rq;
+}
+impl<N, D, Limit, Lp, Lq, Rp, Rq> PrivateSBMove<Greater> for (N, D, Limit, Lp, Lq, Rp, Rq)
+where
+    Lp: Add<Rp>,
+    Lq: Add<Rq>,
+    (N, D, Limit, Sum<Lp, Rp>, Sum<Lq, Rq>, Rp, Rq): PrivateSBDescend,
+{
+    type Path = TArr<B1, <(N, D, Limit, Sum<Lp, Rp>, Sum<Lq, Rq>, Rp, Rq) as PrivateSBDescend>::Path>;
+    type Found = <(N, D, Limit, Sum<Lp, Rp>, Sum<Lq, Rq>, Rp, Rq) as PrivateSBDescend>::Found;
+    type Lp = <(N, D, Limit, Sum<Lp, Rp>, Sum<Lq, Rq>, Rp, Rq) as PrivateSBDescend>::Lp;
+    type Lq = <(N, D, Limit, Sum<Lp, Rp>, Sum<Lq, Rq>, Rp, Rq) as PrivateSBDescend>::Lq;
+    type Rp = <(N, D, Limit, Sum<Lp, Rp>, Sum<Lq, Rq>, Rp, Rq) as PrivateSBDescend>::Rp;
+    type Rq = <(N, D, Limit, Sum<Lp, Rp>, Sum<Lq, Rq>, Rp, Rq) as PrivateSBDescend>::Rq;
+}
+
+/// The parent is the most recent bound, which is the one with the biggest `p + q`.
+#[doc(hidden)]
+pub trait PrivateSBParent {
+    type Output;
+}
+impl<Lp, Lq, Rp, Rq> PrivateSBParent for (Lp, Lq, Rp, Rq)
+where
+    Lp: Add<Lq>,
+    Rp: Add<Rq>,
+    Sum<Lp, Lq>: Cmp<Sum<Rp, Rq>>,
+    (Lp, Lq, Rp, Rq): PrivateSBParentChoice<Compare<Sum<Lp, Lq>, Sum<Rp, Rq>>>,
+{
+    type Output = <(Lp, Lq, Rp, Rq) as PrivateSBParentChoice<Compare<Sum<Lp, Lq>, Sum<Rp, Rq>>>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateSBParentChoice<Cmp> {
+    type Output;
+}
+impl<Lp: Unsigned + NonZero, Lq: Unsigned + NonZero, Rp, Rq> PrivateSBParentChoice<Greater> for (Lp, Lq, Rp, Rq) {
+    type Output = R<PInt<Lp>, Lq>;
+}
+impl<Lp, Lq, Rp: Unsigned + NonZero, Rq: Unsigned + NonZero> PrivateSBParentChoice<Less> for (Lp, Lq, Rp, Rq) {
+    type Output = R<PInt<Rp>, Rq>;
+}
+
+/// Farey neighbours from the final state of the descent.
+#[doc(hidden)]
+pub trait PrivateFarey<Found> {
+    type Left;
+    type Right;
+}
+
+/// The target is between two bounds which are adjacent in the sequence.
+impl<N, D, Order, Lp, Lq, Rp, Rq> PrivateFarey<False> for (N, D, Order, Lp, Lq, Rp, Rq)
+where
+    (N, D, Order, Lp, Lq, Rp, Rq): PrivateSBDescend,
+    <(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Lp: IntoInteger,
+    <(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Rp: IntoInteger,
+    <(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Lq: Unsigned + NonZero,
+    <(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Rq: Unsigned + NonZero,
+{
+    type Left = R<
+        AsInteger<<(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Lp>,
+        <(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Lq,
+    >;
+    type Right = R<
+        AsInteger<<(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Rp>,
+        <(N, D, Order, Lp, Lq, Rp, Rq) as PrivateSBDescend>::Rq,
+    >;
+}
+
+/// Neighbour `(P + K*N)/(Q + K*D)` of `N/D` on the side of the bound `P/Q`, with the biggest `K` keeping the denominator at most `Order`.
+#[doc(hidden)]
+pub type PrivateFareyNeighbour<N, D, Order, P, Q> = ToRational<
+    AsInteger<Sum<P, Prod<N, Quot<Diff<Order, Q>, D>>>>,
+    Sum<Q, Prod<D, Quot<Diff<Order, Q>, D>>>,
+>;
+
+/// The target is in the sequence, its neighbours are the furthest descendants of the bounds in the sequence.
+impl<N, D, Order, Lp, Lq, Rp, Rq> PrivateFarey<True> for (N, D, Order, Lp, Lq, Rp, Rq)
+where
+    (N, D, Order, Lp, Lq, Rp, Rq): PrivateSBDescend,
+    PrivateSBBounds<(N, D, Order, Lp, Lq, Rp, Rq)>: PrivateFareyFound<N, D, Order>,
+{
+    type Left = <PrivateSBBounds<(N, D, Order, Lp, Lq, Rp, Rq)> as PrivateFareyFound<N, D, Order>>::Left;
+    type Right = <PrivateSBBounds<(N, D, Order, Lp, Lq, Rp, Rq)> as PrivateFareyFound<N, D, Order>>::Right;
+}
+
+#[doc(hidden)]
+pub trait PrivateFareyFound<N, D, Order> {
+    type Left;
+    type Right;
+}
+impl<N, D, Order, Lp, Lq, Rp, Rq> PrivateFareyFound<N, D, Order> for (Lp, Lq, Rp, Rq)
+where
+    Order: Sub<Lq> + Sub<Rq>,
+    Diff<Order, Lq>: Div<D>,
+    Diff<Order, Rq>: Div<D>,
+    N: Mul<Quot<Diff<Order, Lq>, D>> + Mul<Quot<Diff<Order, Rq>, D>>,
+    D: Mul<Quot<Diff<Order, Lq>, D>> + Mul<Quot<Diff<Order, Rq>, D>>,
+    Lp: Add<Prod<N, Quot<Diff<Order, Lq>, D>>>,
+    Lq: Add<Prod<D, Quot<Diff<Order, Lq>, D>>>,
+    Rp: Add<Prod<N, Quot<Diff<Order, Rq>, D>>>,
+    Rq: Add<Prod<D, Quot<Diff<Order, Rq>, D>>>,
+    Sum<Lp, Prod<N, Quot<Diff<Order, Lq>, D>>>: IntoInteger,
+    Sum<Rp, Prod<N, Quot<Diff<Order, Rq>, D>>>: IntoInteger,
+    Sum<Lq, Prod<D, Quot<Diff<Order, Lq>, D>>>: Unsigned + NonZero,
+    Sum<Rq, Prod<D, Quot<Diff<Order, Rq>, D>>>: Unsigned + NonZero,
+    R<AsInteger<Sum<Lp, Prod<N, Quot<Diff<Order, Lq>, D>>>>, Sum<Lq, Prod<D, Quot<Diff<Order, Lq>, D>>>>: Simplify,
+    R<AsInteger<Sum<Rp, Prod<N, Quot<Diff<Order, Rq>, D>>>>, Sum<Rq, Prod<D, Quot<Diff<Order, Rq>, D>>>>: Simplify,
+{
+    type Left = PrivateFareyNeighbour<N, D, Order, Lp, Lq>;
+    type Right = PrivateFareyNeighbour<N, D, Order, Rp, Rq>;
+}
@@ -22,5 +22,38 @@ impl<Then, Else> TypeIf<Then, Else> for False {
 mod zero;
 pub use zero::*;
 
+mod lcm;
+pub use lcm::*;
+
+mod primes;
+pub use primes::*;
+
+mod combinatorics;
+pub use combinatorics::*;
+
+mod division;
+pub use division::*;
+
+mod bitwise;
+pub use bitwise::*;
+
+mod fixed_width;
+pub use fixed_width::*;
+
+mod subtraction;
+pub use subtraction::*;
+
+mod bits;
+pub use bits::*;
+
+mod digits;
+pub use digits::*;
+
 mod type_display;
 pub use type_display::*;
+
+mod type_name;
+pub use type_name::*;
+
+mod pretty_name;
+pub use pretty_name::*;
@@ -0,0 +1,240 @@
+//! Bit manipulation operators on [Unsigned] numbers.
+//!
+//! The bit length, bit access and bit setting operators come from typenum (see [Len], [GetBit] and [SetBit](typenum::uint::SetBit)),
+//! and have aliases in [operator_aliases_extended](crate::operator_aliases_extended) along with the ones defined here.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_type_eq!(CountOnes<U13>, U3);
+//! assert_type_eq!(TrailingZeros<U12>, U2);
+//! assert_type_eq!(BitLength<U12>, U4);
+//! assert_type_eq!(IsPowerOfTwo<U12>, False);
+//! assert_type_eq!(NextPowerOfTwo<U12>, U16);
+//! assert_type_eq!(PrevPowerOfTwo<U12>, U8);
+//! assert_type_eq!(ReverseBits<U1, U8>, U128);
+//! assert_type_eq!(GetBit<U12, U2>, True);
+//! assert_type_eq!(SetBit<U12, U0, True>, U13);
+//! ```
+
+use std::ops::{Add, Shl, Shr, Sub};
+
+use typenum::uint::GetBit;
+use typenum::{
+    Add1, Bit, False, IsEqual, Len, Length, Shleft, Shright, Sub1, Sum, True, UInt, UTerm,
+    Unsigned, B0, B1, U0, U1,
+};
+
+/// Counts the ones in the binary representation of an [Unsigned] number.
+///
+/// More convinient syntax with the associated [CountOnes](crate::operator_aliases_extended::CountOnes) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(CountOnes<U0>, U0);
+/// assert_type_eq!(CountOnes<U1>, U1);
+/// assert_type_eq!(CountOnes<U255>, U8);
+/// assert_type_eq!(CountOnes<U256>, U1);
+/// ```
+pub trait PopCount {
+    /// The number of ones.
+    type Output;
+}
+
+impl PopCount for UTerm {
+    type Output = U0;
+}
+
+impl<U: Unsigned + PopCount> PopCount for UInt<U, B0> {
+    type Output = <U as PopCount>::Output;
+}
+
+impl<U: Unsigned + PopCount> PopCount for UInt<U, B1>
+where
+    <U as PopCount>::Output: Add<B1>,
+{
+    type Output = Add1<<U as PopCount>::Output>;
+}
+
+/// Counts the zeros after the lowest one in the binary representation of an [Unsigned] number.
+///
+/// It is not implemented for [U0].
+///
+/// More convinient syntax with the associated [TrailingZeros](crate::operator_aliases_extended::TrailingZeros) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(TrailingZeros<U1>, U0);
+/// assert_type_eq!(TrailingZeros<U40>, U3);
+/// assert_type_eq!(TrailingZeros<U1024>, U10);
+/// // type Fail = TrailingZeros<U0>; Infinitely many zeros.
+/// ```
+pub trait TrailingZeroCount {
+    /// The number of trailing zeros.
+    type Output;
+}
+
+impl<U: Unsigned> TrailingZeroCount for UInt<U, B1> {
+    type Output = U0;
+}
+
+impl<U: Unsigned + TrailingZeroCount> TrailingZeroCount for UInt<U, B0>
+where
+    <U as TrailingZeroCount>::Output: Add<B1>,
+{
+    type Output = Add1<<U as TrailingZeroCount>::Output>;
+}
+
+/// Tells whether an [Unsigned] number is a power of two, giving [True] or [False].
+///
+/// More convinient syntax with the associated [IsPowerOfTwo](crate::operator_aliases_extended::IsPowerOfTwo) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IsPowerOfTwo<U0>, False);
+/// assert_type_eq!(IsPowerOfTwo<U1>, True);
+/// assert_type_eq!(IsPowerOfTwo<U64>, True);
+/// assert_type_eq!(IsPowerOfTwo<U96>, False);
+/// ```
+pub trait PowerOfTwoTest {
+    /// [True] or [False].
+    type Output;
+}
+
+impl<U: Unsigned + PopCount> PowerOfTwoTest for U
+where
+    <U as PopCount>::Output: IsEqual<U1>,
+{
+    type Output = <<U as PopCount>::Output as IsEqual<U1>>::Output;
+}
+
+/// Computes the smallest power of two greater than or equal to an [Unsigned] number.
+///
+/// More convinient syntax with the associated [NextPowerOfTwo](crate::operator_aliases_extended::NextPowerOfTwo) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(NextPowerOfTwo<U0>, U1);
+/// assert_type_eq!(NextPowerOfTwo<U1>, U1);
+/// assert_type_eq!(NextPowerOfTwo<U3>, U4);
+/// assert_type_eq!(NextPowerOfTwo<U64>, U64);
+/// assert_type_eq!(NextPowerOfTwo<U65>, U128);
+/// ```
+pub trait CeilPowerOfTwo {
+    /// The power of two.
+    type Output;
+}
+
+impl<U: Unsigned + PowerOfTwoTest> CeilPowerOfTwo for U
+where
+    U: PrivateCeilPowerOfTwo<<U as PowerOfTwoTest>::Output>,
+{
+    type Output = <U as PrivateCeilPowerOfTwo<<U as PowerOfTwoTest>::Output>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateCeilPowerOfTwo<IsPowerOfTwo> {
+    type Output;
+}
+impl<U> PrivateCeilPowerOfTwo<True> for U {
+    type Output = U;
+}
+/// `2^len(u)`
+impl<U: Len> PrivateCeilPowerOfTwo<False> for U
+where
+    U1: Shl<Length<U>>,
+{
+    type Output = Shleft<U1, Length<U>>;
+}
+
+/// Computes the largest power of two less than or equal to an [Unsigned] number.
+///
+/// It is not implemented for [U0].
+///
+/// More convinient syntax with the associated [PrevPowerOfTwo](crate::operator_aliases_extended::PrevPowerOfTwo) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(PrevPowerOfTwo<U1>, U1);
+/// assert_type_eq!(PrevPowerOfTwo<U3>, U2);
+/// assert_type_eq!(PrevPowerOfTwo<U64>, U64);
+/// assert_type_eq!(PrevPowerOfTwo<U127>, U64);
+/// // type Fail = PrevPowerOfTwo<U0>;
+/// ```
+pub trait FloorPowerOfTwo {
+    /// The power of two.
+    type Output;
+}
+
+/// `2^(len(u) - 1)`
+impl<U: Unsigned, B: Bit> FloorPowerOfTwo for UInt<U, B>
+where
+    UInt<U, B>: Len,
+    Length<UInt<U, B>>: Sub<B1>,
+    U1: Shl<Sub1<Length<UInt<U, B>>>>,
+{
+    type Output = Shleft<U1, Sub1<Length<UInt<U, B>>>>;
+}
+
+/// Reverses the order of the `W` lowest bits of an [Unsigned] number. Higher bits are dropped.
+///
+/// More convinient syntax with the associated [ReverseBits](crate::operator_aliases_extended::ReverseBits) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ReverseBits<U1, U4>, U8);
+/// assert_type_eq!(ReverseBits<U6, U4>, U6);
+/// assert_type_eq!(ReverseBits<U11, U4>, U13);
+/// assert_type_eq!(ReverseBits<U11, U3>, U6);
+/// assert_type_eq!(ReverseBits<U0, U8>, U0);
+/// assert_type_eq!(ReverseBits<U5, U0>, U0);
+/// ```
+pub trait BitReversal<W> {
+    /// The reversed number.
+    type Output;
+}
+
+impl<U: Unsigned, W> BitReversal<W> for U
+where
+    (U, U0): PrivateReverseBits<W>,
+{
+    type Output = <(U, U0) as PrivateReverseBits<W>>::Output;
+}
+
+/// Moves the lowest bit of `U` into `Acc`, `W` times. Implemented for `(U, Acc)`.
+#[doc(hidden)]
+pub trait PrivateReverseBits<W> {
+    type Output;
+}
+impl<U, Acc> PrivateReverseBits<UTerm> for (U, Acc) {
+    type Output = Acc;
+}
+impl<U, Acc, Uw: Unsigned, Bw: Bit> PrivateReverseBits<UInt<Uw, Bw>> for (U, Acc)
+where
+    U: GetBit<U0> + Shr<B1>,
+    Acc: Shl<B1>,
+    Shleft<Acc, B1>: Add<<U as GetBit<U0>>::Output>,
+    UInt<Uw, Bw>: Sub<B1>,
+    (
+        Shright<U, B1>,
+        Sum<Shleft<Acc, B1>, <U as GetBit<U0>>::Output>,
+    ): PrivateReverseBits<Sub1<UInt<Uw, Bw>>>,
+{
+    type Output = <(
+        Shright<U, B1>,
+        Sum<Shleft<Acc, B1>, <U as GetBit<U0>>::Output>,
+    ) as PrivateReverseBits<Sub1<UInt<Uw, Bw>>>>::Output;
+}
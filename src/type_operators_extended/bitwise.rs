@@ -0,0 +1,404 @@
+//! Bitwise operators on [Integer]s.
+//!
+//! typenum's bitwise operators only exist for [Unsigned] numbers, and the orphan rule prevents implementing [BitAnd] and friends
+//! on typenum's [Integer]s here. This module provides them as separate type operators, with infinite-precision two's-complement
+//! semantics: negative numbers behave as if they had infinitely many leading ones.
+//! [CrossInt](crate::CrossInt) implements the standard operators with them.
+//!
+//! The module also provides conversions between an [Integer] and its fixed-width two's-complement [Unsigned] encoding.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_type_eq!(IntAnd<N6, P7>, P2);
+//! assert_type_eq!(IntOr<N6, P7>, N1);
+//! assert_type_eq!(IntXor<N6, P7>, N3);
+//! assert_type_eq!(IntNot<P5>, N6);
+//! assert_type_eq!(IntShl<N3, U2>, N12);
+//! assert_type_eq!(IntShr<N5, U1>, N3);
+//!
+//! // A 4-bit signed field.
+//! assert_type_eq!(ToTwosComplement<N3, U4>, U13);
+//! assert_type_eq!(FromTwosComplement<U13, U4>, N3);
+//!
+//! assert_type_eq!(And<CrossInt<N6>, U7>, CrossInt<P2>);
+//! assert_type_eq!(Shright<CrossInt<N5>, U1>, CrossInt<N3>);
+//! ```
+
+use std::ops::{Add, BitAnd, BitOr, BitXor, Neg, Rem, Shl, Shr, Sub};
+
+use typenum::{
+    Add1, And, Cmp, Compare, Diff, Equal, Greater, Integer, Less, NInt, Negate, NonZero, Or, PInt, Shleft, Shright,
+    Sub1, UTerm, Unsigned, Xor, B0, B1, N1, U1, Z0,
+};
+
+use crate::{AsInteger, IntoInteger, PrivateReduce};
+
+/// Computes the bitwise and of two [Integer]s, in two's complement.
+///
+/// More convinient syntax with the associated [IntAnd](crate::operator_aliases_extended::IntAnd) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IntAnd<P12, P10>, P8);
+/// assert_type_eq!(IntAnd<N1, P10>, P10);
+/// assert_type_eq!(IntAnd<P10, N4>, P8);
+/// assert_type_eq!(IntAnd<N6, N3>, N8);
+/// assert_type_eq!(IntAnd<Z0, N3>, Z0);
+/// ```
+pub trait IntegerBitAnd<Rhs> {
+    /// The bitwise and.
+    type Output;
+}
+
+/// Computes the bitwise or of two [Integer]s, in two's complement.
+///
+/// More convinient syntax with the associated [IntOr](crate::operator_aliases_extended::IntOr) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IntOr<P12, P10>, P14);
+/// assert_type_eq!(IntOr<N8, P3>, N5);
+/// assert_type_eq!(IntOr<P3, N8>, N5);
+/// assert_type_eq!(IntOr<N6, N3>, N1);
+/// assert_type_eq!(IntOr<Z0, N3>, N3);
+/// ```
+pub trait IntegerBitOr<Rhs> {
+    /// The bitwise or.
+    type Output;
+}
+
+/// Computes the bitwise exclusive or of two [Integer]s, in two's complement.
+///
+/// More convinient syntax with the associated [IntXor](crate::operator_aliases_extended::IntXor) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IntXor<P12, P10>, P6);
+/// assert_type_eq!(IntXor<N1, P10>, N11);
+/// assert_type_eq!(IntXor<N6, N3>, P7);
+/// assert_type_eq!(IntXor<N3, N3>, Z0);
+/// ```
+pub trait IntegerBitXor<Rhs> {
+    /// The bitwise exclusive or.
+    type Output;
+}
+
+/// Computes the bitwise not of an [Integer], in two's complement: `!x = -x - 1`.
+///
+/// More convinient syntax with the associated [IntNot](crate::operator_aliases_extended::IntNot) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IntNot<Z0>, N1);
+/// assert_type_eq!(IntNot<N1>, Z0);
+/// assert_type_eq!(IntNot<P5>, N6);
+/// ```
+pub trait IntegerNot {
+    /// The bitwise not.
+    type Output;
+}
+
+/// Shifts an [Integer] to the left by an [Unsigned] amount, that is multiplies it by `2^Shift`.
+///
+/// More convinient syntax with the associated [IntShl](crate::operator_aliases_extended::IntShl) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IntShl<P3, U2>, P12);
+/// assert_type_eq!(IntShl<N3, U0>, N3);
+/// assert_type_eq!(IntShl<Z0, U4>, Z0);
+/// ```
+pub trait IntegerShl<Shift> {
+    /// The shifted integer.
+    type Output;
+}
+
+/// Arithmetic right shift of an [Integer] by an [Unsigned] amount, that is division by `2^Shift` rounded toward negative infinity.
+///
+/// More convinient syntax with the associated [IntShr](crate::operator_aliases_extended::IntShr) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IntShr<P13, U2>, P3);
+/// assert_type_eq!(IntShr<P3, U2>, Z0);
+/// assert_type_eq!(IntShr<N13, U2>, N4);
+/// assert_type_eq!(IntShr<N1, U10>, N1);
+/// assert_type_eq!(IntShr<N8, U3>, N1);
+/// ```
+pub trait IntegerShr<Shift> {
+    /// The shifted integer.
+    type Output;
+}
+
+/// Encodes an [Integer] into the [Unsigned] made of its `Bits` lowest two's-complement bits.
+///
+/// Integers which don't fit in `Bits` bits wrap around, like `as` casts.
+///
+/// More convinient syntax with the associated [ToTwosComplement](crate::operator_aliases_extended::ToTwosComplement) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ToTwosComplement<P3, U8>, U3);
+/// assert_type_eq!(ToTwosComplement<N1, U8>, U255);
+/// assert_type_eq!(ToTwosComplement<N128, U8>, U128);
+/// assert_type_eq!(ToTwosComplement<Z0, U8>, U0);
+/// assert_type_eq!(ToTwosComplement<P200, U4>, U8);
+/// ```
+pub trait TwosComplementEncode<Bits> {
+    /// The encoding.
+    type Output;
+}
+
+/// Decodes the `Bits` lowest bits of an [Unsigned] as a two's-complement [Integer].
+///
+/// It is not implemented for zero `Bits`.
+///
+/// More convinient syntax with the associated [FromTwosComplement](crate::operator_aliases_extended::FromTwosComplement) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(FromTwosComplement<U3, U8>, P3);
+/// assert_type_eq!(FromTwosComplement<U255, U8>, N1);
+/// assert_type_eq!(FromTwosComplement<U127, U8>, P127);
+/// assert_type_eq!(FromTwosComplement<U128, U8>, N128);
+/// assert_type_eq!(FromTwosComplement<U0, U8>, Z0);
+/// assert_type_eq!(FromTwosComplement<U1, U1>, N1);
+/// assert_type_eq!(FromTwosComplement<U29, U4>, N3);
+/// ```
+pub trait TwosComplementDecode<Bits> {
+    /// The decoded integer.
+    type Output;
+}
+
+/// Splits an [Integer] into its sign bit and its bits: the number itself if non-negative, its complement `-x - 1` otherwise.
+#[doc(hidden)]
+pub trait PrivateTwosEncode {
+    type Sign;
+    type Bits;
+}
+impl PrivateTwosEncode for Z0 {
+    type Sign = B0;
+    type Bits = UTerm;
+}
+impl<U: Unsigned + NonZero> PrivateTwosEncode for PInt<U> {
+    type Sign = B0;
+    type Bits = U;
+}
+impl<U: Unsigned + NonZero + Sub<B1>> PrivateTwosEncode for NInt<U> {
+    type Sign = B1;
+    type Bits = Sub1<U>;
+}
+
+/// Inverse of [PrivateTwosEncode], implemented for `(Sign, Bits)`.
+#[doc(hidden)]
+pub trait PrivateTwosDecode {
+    type Output;
+}
+impl<U: IntoInteger> PrivateTwosDecode for (B0, U) {
+    type Output = AsInteger<U>;
+}
+impl<C: Add<B1>> PrivateTwosDecode for (B1, C)
+where
+    Add1<C>: IntoInteger,
+    AsInteger<Add1<C>>: Neg,
+{
+    type Output = Negate<AsInteger<Add1<C>>>;
+}
+
+/// Two's-complement binary operators on `(SignL, BitsL, SignR, BitsR)`, the output being `(Sign, Bits)`.
+#[doc(hidden)]
+pub trait PrivateTwosAnd {
+    type Output;
+}
+#[doc(hidden)]
+pub trait PrivateTwosOr {
+    type Output;
+}
+#[doc(hidden)]
+pub trait PrivateTwosXor {
+    type Output;
+}
+
+// `a & b`
+impl<A: BitAnd<B>, B> PrivateTwosAnd for (B0, A, B0, B) {
+    type Output = (B0, And<A, B>);
+}
+/// `a & !c = a ^ (a & c)`
+impl<A, C> PrivateTwosAnd for (B0, A, B1, C)
+where
+    A: BitAnd<C> + BitXor<And<A, C>>,
+{
+    type Output = (B0, Xor<A, And<A, C>>);
+}
+impl<A, C> PrivateTwosAnd for (B1, C, B0, A)
+where
+    (B0, A, B1, C): PrivateTwosAnd,
+{
+    type Output = <(B0, A, B1, C) as PrivateTwosAnd>::Output;
+}
+/// `!c & !d = !(c | d)`
+impl<C: BitOr<D>, D> PrivateTwosAnd for (B1, C, B1, D) {
+    type Output = (B1, Or<C, D>);
+}
+
+// `a | b`
+impl<A: BitOr<B>, B> PrivateTwosOr for (B0, A, B0, B) {
+    type Output = (B0, Or<A, B>);
+}
+/// `a | !c = !(c & !a) = !(c ^ (c & a))`
+impl<A, C> PrivateTwosOr for (B0, A, B1, C)
+where
+    C: BitAnd<A> + BitXor<And<C, A>>,
+{
+    type Output = (B1, Xor<C, And<C, A>>);
+}
+impl<A, C> PrivateTwosOr for (B1, C, B0, A)
+where
+    (B0, A, B1, C): PrivateTwosOr,
+{
+    type Output = <(B0, A, B1, C) as PrivateTwosOr>::Output;
+}
+/// `!c | !d = !(c & d)`
+impl<C: BitAnd<D>, D> PrivateTwosOr for (B1, C, B1, D) {
+    type Output = (B1, And<C, D>);
+}
+
+// `a ^ b`
+impl<A: BitXor<B>, B> PrivateTwosXor for (B0, A, B0, B) {
+    type Output = (B0, Xor<A, B>);
+}
+/// `a ^ !c = !(a ^ c)`
+impl<A: BitXor<C>, C> PrivateTwosXor for (B0, A, B1, C) {
+    type Output = (B1, Xor<A, C>);
+}
+impl<A, C> PrivateTwosXor for (B1, C, B0, A)
+where
+    (B0, A, B1, C): PrivateTwosXor,
+{
+    type Output = <(B0, A, B1, C) as PrivateTwosXor>::Output;
+}
+/// `!c ^ !d = c ^ d`
+impl<C: BitXor<D>, D> PrivateTwosXor for (B1, C, B1, D) {
+    type Output = (B0, Xor<C, D>);
+}
+
+macro_rules! impl_bitwise {
+    ($Trait:ident => $Private:ident) => {
+        impl<A: Integer, B: Integer> $Trait<B> for A
+        where
+            A: PrivateTwosEncode,
+            B: PrivateTwosEncode,
+            (A::Sign, A::Bits, B::Sign, B::Bits): $Private,
+            <(A::Sign, A::Bits, B::Sign, B::Bits) as $Private>::Output: PrivateTwosDecode,
+        {
+            type Output = <<(A::Sign, A::Bits, B::Sign, B::Bits) as $Private>::Output as PrivateTwosDecode>::Output;
+        }
+    };
+}
+
+impl_bitwise! {IntegerBitAnd => PrivateTwosAnd}
+impl_bitwise! {IntegerBitOr => PrivateTwosOr}
+impl_bitwise! {IntegerBitXor => PrivateTwosXor}
+
+impl<I: Integer> IntegerNot for I
+where
+    N1: Sub<I>,
+{
+    type Output = Diff<N1, I>;
+}
+
+impl<S: Unsigned> IntegerShl<S> for Z0 {
+    type Output = Z0;
+}
+impl<U: Unsigned + NonZero + Shl<S>, S: Unsigned> IntegerShl<S> for PInt<U>
+where
+    Shleft<U, S>: Unsigned + NonZero,
+{
+    type Output = PInt<Shleft<U, S>>;
+}
+impl<U: Unsigned + NonZero + Shl<S>, S: Unsigned> IntegerShl<S> for NInt<U>
+where
+    Shleft<U, S>: Unsigned + NonZero,
+{
+    type Output = NInt<Shleft<U, S>>;
+}
+
+/// Shifting the complement of a negative number gives the complement of the result.
+impl<I: Integer, S: Unsigned> IntegerShr<S> for I
+where
+    I: PrivateTwosEncode,
+    I::Bits: Shr<S>,
+    (I::Sign, Shright<I::Bits, S>): PrivateTwosDecode,
+{
+    type Output = <(I::Sign, Shright<I::Bits, S>) as PrivateTwosDecode>::Output;
+}
+
+impl<I: Integer, Bits: Unsigned> TwosComplementEncode<Bits> for I
+where
+    U1: Shl<Bits>,
+    I: PrivateReduce<Shleft<U1, Bits>>,
+{
+    type Output = <I as PrivateReduce<Shleft<U1, Bits>>>::Output;
+}
+
+impl<U: Unsigned, Bits: Unsigned + NonZero> TwosComplementDecode<Bits> for U
+where
+    U1: Shl<Bits>,
+    Shleft<U1, Bits>: Shr<B1>,
+    (U, Shleft<U1, Bits>, Shright<Shleft<U1, Bits>, B1>): PrivateTwosTruncate,
+{
+    type Output = <(U, Shleft<U1, Bits>, Shright<Shleft<U1, Bits>, B1>) as PrivateTwosTruncate>::Output;
+}
+
+/// Truncates `U` modulo `Full` and compares it with the sign bit `Half`. Implemented for `(U, Full, Half)`.
+#[doc(hidden)]
+pub trait PrivateTwosTruncate {
+    type Output;
+}
+impl<U, Full, Half> PrivateTwosTruncate for (U, Full, Half)
+where
+    U: Rem<Full>,
+    <U as Rem<Full>>::Output: Cmp<Half>,
+    <U as Rem<Full>>::Output: PrivateTwosSign<Compare<<U as Rem<Full>>::Output, Half>, Full>,
+{
+    type Output = <<U as Rem<Full>>::Output as PrivateTwosSign<Compare<<U as Rem<Full>>::Output, Half>, Full>>::Output;
+}
+
+/// Decodes a truncated value, `Cmp` being its comparison with the sign bit and `Full` the modulus.
+#[doc(hidden)]
+pub trait PrivateTwosSign<Cmp, Full> {
+    type Output;
+}
+impl<U: IntoInteger, Full> PrivateTwosSign<Less, Full> for U {
+    type Output = AsInteger<U>;
+}
+impl<U: IntoInteger, Full: IntoInteger> PrivateTwosSign<Equal, Full> for U
+where
+    AsInteger<U>: Sub<AsInteger<Full>>,
+{
+    type Output = Diff<AsInteger<U>, AsInteger<Full>>;
+}
+impl<U: IntoInteger, Full: IntoInteger> PrivateTwosSign<Greater, Full> for U
+where
+    AsInteger<U>: Sub<AsInteger<Full>>,
+{
+    type Output = Diff<AsInteger<U>, AsInteger<Full>>;
+}
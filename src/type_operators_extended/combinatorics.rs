@@ -0,0 +1,204 @@
+//! Factorials and binomial coefficients.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use typenum::{Add1, Bit, Diff, Integer, NonZero, PInt, Prod, Quot, Sub1, Sum, UInt, UTerm, Unsigned, B1, P1, U1};
+
+use crate::R;
+
+/// Computes the factorial of an [Unsigned] number.
+///
+/// More convinient syntax with the associated [Factorial](crate::operator_aliases_extended::Factorial) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Factorial<U0>, U1);
+/// assert_type_eq!(Factorial<U1>, U1);
+/// assert_type_eq!(Factorial<U5>, U120);
+/// assert_eq!(<Factorial<U10> as Unsigned>::USIZE, 3628800);
+/// ```
+pub trait GetFactorial {
+    /// The factorial of `Self`.
+    type Output;
+}
+
+impl GetFactorial for UTerm {
+    type Output = U1;
+}
+
+impl<U: Unsigned, B: Bit> GetFactorial for UInt<U, B>
+where
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: GetFactorial,
+    UInt<U, B>: Mul<<Sub1<UInt<U, B>> as GetFactorial>::Output>,
+{
+    type Output = Prod<UInt<U, B>, <Sub1<UInt<U, B>> as GetFactorial>::Output>;
+}
+
+/// Computes the falling factorial `X * (X - 1) * ... * (X - K + 1)`, with `K` factors.
+///
+/// It is implemented for [Unsigned] and rational `X`, `K` being [Unsigned].
+///
+/// More convinient syntax with the associated [FallingFactorial](crate::operator_aliases_extended::FallingFactorial) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(FallingFactorial<U5, U0>, U1);
+/// assert_type_eq!(FallingFactorial<U5, U2>, U20);
+/// assert_type_eq!(FallingFactorial<U5, U5>, U120);
+/// assert_type_eq!(FallingFactorial<U3, U5>, U0);
+/// assert_type_eq!(FallingFactorial<rational!(P1, U2), U3>, rational!(P3, U8));
+/// ```
+pub trait GetFallingFactorial<K> {
+    /// The falling factorial.
+    type Output;
+}
+
+impl GetFallingFactorial<UTerm> for UTerm {
+    type Output = U1;
+}
+
+impl<U: Unsigned, B: Bit> GetFallingFactorial<UTerm> for UInt<U, B> {
+    type Output = U1;
+}
+
+impl<Uk: Unsigned, Bk: Bit> GetFallingFactorial<UInt<Uk, Bk>> for UTerm {
+    type Output = UTerm;
+}
+
+impl<U: Unsigned, B: Bit, Uk: Unsigned, Bk: Bit> GetFallingFactorial<UInt<Uk, Bk>> for UInt<U, B>
+where
+    UInt<U, B>: Sub<B1>,
+    UInt<Uk, Bk>: Sub<B1>,
+    Sub1<UInt<U, B>>: GetFallingFactorial<Sub1<UInt<Uk, Bk>>>,
+    UInt<U, B>: Mul<<Sub1<UInt<U, B>> as GetFallingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>,
+{
+    type Output = Prod<UInt<U, B>, <Sub1<UInt<U, B>> as GetFallingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>;
+}
+
+impl<N: Integer, D: Unsigned + NonZero> GetFallingFactorial<UTerm> for R<N, D> {
+    type Output = R<P1, U1>;
+}
+
+impl<N: Integer, D: Unsigned + NonZero, Uk: Unsigned, Bk: Bit> GetFallingFactorial<UInt<Uk, Bk>> for R<N, D>
+where
+    R<N, D>: Sub<R<P1, U1>>,
+    UInt<Uk, Bk>: Sub<B1>,
+    Diff<R<N, D>, R<P1, U1>>: GetFallingFactorial<Sub1<UInt<Uk, Bk>>>,
+    R<N, D>: Mul<<Diff<R<N, D>, R<P1, U1>> as GetFallingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>,
+{
+    type Output = Prod<R<N, D>, <Diff<R<N, D>, R<P1, U1>> as GetFallingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>;
+}
+
+/// Computes the rising factorial `X * (X + 1) * ... * (X + K - 1)`, with `K` factors.
+///
+/// It is implemented for [Unsigned] and rational `X`, `K` being [Unsigned].
+///
+/// More convinient syntax with the associated [RisingFactorial](crate::operator_aliases_extended::RisingFactorial) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(RisingFactorial<U3, U0>, U1);
+/// assert_type_eq!(RisingFactorial<U3, U3>, U60);
+/// assert_type_eq!(RisingFactorial<U0, U3>, U0);
+/// assert_type_eq!(RisingFactorial<rational!(P1, U2), U2>, rational!(P3, U4));
+/// assert_type_eq!(RisingFactorial<rational!(N2), U3>, rational!(Z0));
+/// ```
+pub trait GetRisingFactorial<K> {
+    /// The rising factorial.
+    type Output;
+}
+
+impl GetRisingFactorial<UTerm> for UTerm {
+    type Output = U1;
+}
+
+impl<U: Unsigned, B: Bit> GetRisingFactorial<UTerm> for UInt<U, B> {
+    type Output = U1;
+}
+
+impl<Uk: Unsigned, Bk: Bit> GetRisingFactorial<UInt<Uk, Bk>> for UTerm {
+    type Output = UTerm;
+}
+
+impl<U: Unsigned, B: Bit, Uk: Unsigned, Bk: Bit> GetRisingFactorial<UInt<Uk, Bk>> for UInt<U, B>
+where
+    UInt<U, B>: Add<B1>,
+    UInt<Uk, Bk>: Sub<B1>,
+    Add1<UInt<U, B>>: GetRisingFactorial<Sub1<UInt<Uk, Bk>>>,
+    UInt<U, B>: Mul<<Add1<UInt<U, B>> as GetRisingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>,
+{
+    type Output = Prod<UInt<U, B>, <Add1<UInt<U, B>> as GetRisingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>;
+}
+
+impl<N: Integer, D: Unsigned + NonZero> GetRisingFactorial<UTerm> for R<N, D> {
+    type Output = R<P1, U1>;
+}
+
+impl<N: Integer, D: Unsigned + NonZero, Uk: Unsigned, Bk: Bit> GetRisingFactorial<UInt<Uk, Bk>> for R<N, D>
+where
+    R<N, D>: Add<R<P1, U1>>,
+    UInt<Uk, Bk>: Sub<B1>,
+    Sum<R<N, D>, R<P1, U1>>: GetRisingFactorial<Sub1<UInt<Uk, Bk>>>,
+    R<N, D>: Mul<<Sum<R<N, D>, R<P1, U1>> as GetRisingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>,
+{
+    type Output = Prod<R<N, D>, <Sum<R<N, D>, R<P1, U1>> as GetRisingFactorial<Sub1<UInt<Uk, Bk>>>>::Output>;
+}
+
+/// Computes the binomial coefficient `X` choose `K`, that is `FallingFactorial<X, K> / Factorial<K>`.
+///
+/// It is implemented for [Unsigned] `X`, and for rational `X` as the generalised binomial coefficient.
+///
+/// More convinient syntax with the associated [Binomial](crate::operator_aliases_extended::Binomial) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Binomial<U10, U3>, U120);
+/// assert_type_eq!(Binomial<U10, U0>, U1);
+/// assert_type_eq!(Binomial<U10, U10>, U1);
+/// assert_type_eq!(Binomial<U3, U5>, U0);
+///
+/// assert_type_eq!(Binomial<rational!(P10), U3>, rational!(P120));
+/// assert_type_eq!(Binomial<rational!(P1, U2), U2>, rational!(N1, U8));
+/// assert_type_eq!(Binomial<rational!(N1), U3>, rational!(N1));
+/// ```
+pub trait GetBinomial<K> {
+    /// The binomial coefficient.
+    type Output;
+}
+
+impl<K> GetBinomial<K> for UTerm
+where
+    UTerm: GetFallingFactorial<K>,
+    K: GetFactorial,
+    <UTerm as GetFallingFactorial<K>>::Output: Div<<K as GetFactorial>::Output>,
+{
+    type Output = Quot<<UTerm as GetFallingFactorial<K>>::Output, <K as GetFactorial>::Output>;
+}
+
+impl<U: Unsigned, B: Bit, K> GetBinomial<K> for UInt<U, B>
+where
+    UInt<U, B>: GetFallingFactorial<K>,
+    K: GetFactorial,
+    <UInt<U, B> as GetFallingFactorial<K>>::Output: Div<<K as GetFactorial>::Output>,
+{
+    type Output = Quot<<UInt<U, B> as GetFallingFactorial<K>>::Output, <K as GetFactorial>::Output>;
+}
+
+impl<N: Integer, D: Unsigned + NonZero, K> GetBinomial<K> for R<N, D>
+where
+    R<N, D>: GetFallingFactorial<K>,
+    K: GetFactorial,
+    <R<N, D> as GetFallingFactorial<K>>::Output: Div<R<PInt<<K as GetFactorial>::Output>, U1>>,
+    <K as GetFactorial>::Output: Unsigned + NonZero,
+{
+    type Output = Quot<<R<N, D> as GetFallingFactorial<K>>::Output, R<PInt<<K as GetFactorial>::Output>, U1>>;
+}
@@ -0,0 +1,312 @@
+//! Conversions between [Unsigned] numbers and [TArr] lists of bits or digits.
+//!
+//! The order of the lists is given by [MsbFirst] (the default, in reading order) or [LsbFirst].
+//! Zero is represented by a single zero bit or digit, and leading zeros are accepted when converting back.
+//!
+//! The elements of the lists are converted with [IntoBit](crate::IntoBit) and [IntoUnsigned](crate::IntoUnsigned),
+//! so bits can be given as [True](typenum::True), `U1` or `P1` for example.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_type_eq!(ToBits<U11>, tarr![B1, B0, B1, B1]);
+//! assert_type_eq!(ToBits<U11, LsbFirst>, tarr![B1, B1, B0, B1]);
+//! assert_type_eq!(FromBits<tarr![True, False, U1, P1]>, U11);
+//!
+//! assert_type_eq!(ToDigits<U1000, U10>, tarr![U1, U0, U0, U0]);
+//! assert_type_eq!(ToDigits<U255, U16, LsbFirst>, tarr![U15, U15]);
+//! assert_type_eq!(FromDigits<tarr![U1, U0, U2, U4], U10>, U1024);
+//! ```
+
+use std::ops::{Add, Div, Mul, Rem, Shl};
+
+use typenum::{ATerm, Bit, Prod, Quot, Shleft, Sum, TArr, UInt, UTerm, Unsigned, B0, B1, U0};
+
+use crate::{AsBit, AsUnsigned, IntoBit, IntoUnsigned};
+
+/// Marker for lists with the most significant bit or digit first, that is in reading order.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MsbFirst;
+
+/// Marker for lists with the least significant bit or digit first.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LsbFirst;
+
+/// Converts an [Unsigned] number into the [TArr] of its bits, in the given `Order`.
+///
+/// More convinient syntax with the associated [ToBits](crate::operator_aliases_extended::ToBits) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ToBits<U0>, tarr![B0]);
+/// assert_type_eq!(ToBits<U1>, tarr![B1]);
+/// assert_type_eq!(ToBits<U6>, tarr![B1, B1, B0]);
+/// assert_type_eq!(ToBits<U6, LsbFirst>, tarr![B0, B1, B1]);
+/// ```
+pub trait IntoBits<Order> {
+    /// The list of bits.
+    type Output;
+}
+
+impl<Order> IntoBits<Order> for UTerm {
+    type Output = TArr<B0, ATerm>;
+}
+
+impl<U: Unsigned, B: Bit> IntoBits<MsbFirst> for UInt<U, B>
+where
+    UInt<U, B>: PrivateBitsMsb<ATerm>,
+{
+    type Output = <UInt<U, B> as PrivateBitsMsb<ATerm>>::Output;
+}
+
+impl<U: Unsigned, B: Bit> IntoBits<LsbFirst> for UInt<U, B>
+where
+    UInt<U, B>: PrivateBitsLsb,
+{
+    type Output = <UInt<U, B> as PrivateBitsLsb>::Output;
+}
+
+/// Pushes the bits in front of `Acc`, lowest first.
+#[doc(hidden)]
+pub trait PrivateBitsMsb<Acc> {
+    type Output;
+}
+impl<Acc> PrivateBitsMsb<Acc> for UTerm {
+    type Output = Acc;
+}
+impl<U: PrivateBitsMsb<TArr<B, Acc>>, B, Acc> PrivateBitsMsb<Acc> for UInt<U, B> {
+    type Output = <U as PrivateBitsMsb<TArr<B, Acc>>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateBitsLsb {
+    type Output;
+}
+impl PrivateBitsLsb for UTerm {
+    type Output = ATerm;
+}
+impl<U: PrivateBitsLsb, B> PrivateBitsLsb for UInt<U, B> {
+    type Output = TArr<B, <U as PrivateBitsLsb>::Output>;
+}
+
+/// Converts a [TArr] of bits, given in `Order`, into an [Unsigned] number.
+///
+/// The elements are converted with [IntoBit].
+///
+/// More convinient syntax with the associated [FromBits](crate::operator_aliases_extended::FromBits) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(FromBits<tarr![B1, B1, B0]>, U6);
+/// assert_type_eq!(FromBits<tarr![B0, B0, B1, B1, B0]>, U6);
+/// assert_type_eq!(FromBits<tarr![B0, B1, B1], LsbFirst>, U6);
+/// assert_type_eq!(FromBits<tarr![U1, Z0]>, U2);
+/// assert_type_eq!(FromBits<tarr![]>, U0);
+/// ```
+pub trait FromBitList<Order> {
+    /// The number.
+    type Output;
+}
+
+impl<List> FromBitList<MsbFirst> for List
+where
+    List: PrivateFromBitsMsb<U0>,
+{
+    type Output = <List as PrivateFromBitsMsb<U0>>::Output;
+}
+
+impl<List: PrivateFromBitsLsb> FromBitList<LsbFirst> for List {
+    type Output = <List as PrivateFromBitsLsb>::Output;
+}
+
+/// `acc = 2 * acc + bit`, from the most significant bit.
+#[doc(hidden)]
+pub trait PrivateFromBitsMsb<Acc> {
+    type Output;
+}
+impl<Acc> PrivateFromBitsMsb<Acc> for ATerm {
+    type Output = Acc;
+}
+impl<V: IntoBit, Rest, Acc> PrivateFromBitsMsb<Acc> for TArr<V, Rest>
+where
+    Acc: Shl<B1>,
+    Shleft<Acc, B1>: Add<AsBit<V>>,
+    Rest: PrivateFromBitsMsb<Sum<Shleft<Acc, B1>, AsBit<V>>>,
+{
+    type Output = <Rest as PrivateFromBitsMsb<Sum<Shleft<Acc, B1>, AsBit<V>>>>::Output;
+}
+
+/// `bit + 2 * rest`, from the least significant bit.
+#[doc(hidden)]
+pub trait PrivateFromBitsLsb {
+    type Output;
+}
+impl PrivateFromBitsLsb for ATerm {
+    type Output = U0;
+}
+impl<V: IntoBit, Rest: PrivateFromBitsLsb> PrivateFromBitsLsb for TArr<V, Rest>
+where
+    <Rest as PrivateFromBitsLsb>::Output: Shl<B1>,
+    Shleft<<Rest as PrivateFromBitsLsb>::Output, B1>: Add<AsBit<V>>,
+{
+    type Output = Sum<Shleft<<Rest as PrivateFromBitsLsb>::Output, B1>, AsBit<V>>;
+}
+
+/// Converts an [Unsigned] number into the [TArr] of its digits in base `Radix`, in the given `Order`.
+///
+/// The digits are [Unsigned] numbers. It is not implemented for radices below two.
+///
+/// More convinient syntax with the associated [ToDigits](crate::operator_aliases_extended::ToDigits) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ToDigits<U0, U10>, tarr![U0]);
+/// assert_type_eq!(ToDigits<U7, U10>, tarr![U7]);
+/// assert_type_eq!(ToDigits<U100, U10>, tarr![U1, U0, U0]);
+/// assert_type_eq!(ToDigits<U100, U10, LsbFirst>, tarr![U0, U0, U1]);
+/// assert_type_eq!(ToDigits<U100, U16>, tarr![U6, U4]);
+/// assert_type_eq!(ToDigits<U6, U2>, tarr![U1, U1, U0]);
+/// // type Fail = ToDigits<U6, U1>;
+/// ```
+pub trait IntoDigits<Radix, Order> {
+    /// The list of digits.
+    type Output;
+}
+
+impl<Radix: Unsigned, Order> IntoDigits<Radix, Order> for UTerm
+where
+    Radix: PrivateRadix,
+{
+    type Output = TArr<U0, ATerm>;
+}
+
+impl<U: Unsigned, B: Bit, Radix: Unsigned> IntoDigits<Radix, MsbFirst> for UInt<U, B>
+where
+    Radix: PrivateRadix,
+    UInt<U, B>: PrivateDigitsMsb<Radix, ATerm>,
+{
+    type Output = <UInt<U, B> as PrivateDigitsMsb<Radix, ATerm>>::Output;
+}
+
+impl<U: Unsigned, B: Bit, Radix: Unsigned> IntoDigits<Radix, LsbFirst> for UInt<U, B>
+where
+    Radix: PrivateRadix,
+    UInt<U, B>: PrivateDigitsLsb<Radix>,
+{
+    type Output = <UInt<U, B> as PrivateDigitsLsb<Radix>>::Output;
+}
+
+/// Implemented for radices of at least two.
+#[doc(hidden)]
+pub trait PrivateRadix {}
+impl<U: Unsigned, Ba: Bit, Bb: Bit> PrivateRadix for UInt<UInt<U, Ba>, Bb> {}
+
+/// Pushes the digits in front of `Acc`, lowest first.
+#[doc(hidden)]
+pub trait PrivateDigitsMsb<Radix, Acc> {
+    type Output;
+}
+impl<Radix, Acc> PrivateDigitsMsb<Radix, Acc> for UTerm {
+    type Output = Acc;
+}
+impl<U, B, Radix, Acc> PrivateDigitsMsb<Radix, Acc> for UInt<U, B>
+where
+    UInt<U, B>: Div<Radix> + Rem<Radix>,
+    Quot<UInt<U, B>, Radix>: PrivateDigitsMsb<Radix, TArr<<UInt<U, B> as Rem<Radix>>::Output, Acc>>,
+{
+    type Output = <Quot<UInt<U, B>, Radix> as PrivateDigitsMsb<
+        Radix,
+        TArr<<UInt<U, B> as Rem<Radix>>::Output, Acc>,
+    >>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateDigitsLsb<Radix> {
+    type Output;
+}
+impl<Radix> PrivateDigitsLsb<Radix> for UTerm {
+    type Output = ATerm;
+}
+impl<U, B, Radix> PrivateDigitsLsb<Radix> for UInt<U, B>
+where
+    UInt<U, B>: Div<Radix> + Rem<Radix>,
+    Quot<UInt<U, B>, Radix>: PrivateDigitsLsb<Radix>,
+{
+    type Output = TArr<
+        <UInt<U, B> as Rem<Radix>>::Output,
+        <Quot<UInt<U, B>, Radix> as PrivateDigitsLsb<Radix>>::Output,
+    >;
+}
+
+/// Converts a [TArr] of digits in base `Radix`, given in `Order`, into an [Unsigned] number.
+///
+/// The elements are converted with [IntoUnsigned]. They are not checked to be less than `Radix`.
+///
+/// More convinient syntax with the associated [FromDigits](crate::operator_aliases_extended::FromDigits) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(FromDigits<tarr![U1, U0, U0], U10>, U100);
+/// assert_type_eq!(FromDigits<tarr![U0, U0, U1], U10, LsbFirst>, U100);
+/// assert_type_eq!(FromDigits<tarr![P6, P4], U16>, U100);
+/// assert_type_eq!(FromDigits<tarr![], U10>, U0);
+/// ```
+pub trait FromDigitList<Radix, Order> {
+    /// The number.
+    type Output;
+}
+
+impl<List, Radix> FromDigitList<Radix, MsbFirst> for List
+where
+    List: PrivateFromDigitsMsb<Radix, U0>,
+{
+    type Output = <List as PrivateFromDigitsMsb<Radix, U0>>::Output;
+}
+
+impl<List: PrivateFromDigitsLsb<Radix>, Radix> FromDigitList<Radix, LsbFirst> for List {
+    type Output = <List as PrivateFromDigitsLsb<Radix>>::Output;
+}
+
+/// `acc = radix * acc + digit`, from the most significant digit.
+#[doc(hidden)]
+pub trait PrivateFromDigitsMsb<Radix, Acc> {
+    type Output;
+}
+impl<Radix, Acc> PrivateFromDigitsMsb<Radix, Acc> for ATerm {
+    type Output = Acc;
+}
+impl<V: IntoUnsigned, Rest, Radix, Acc> PrivateFromDigitsMsb<Radix, Acc> for TArr<V, Rest>
+where
+    Acc: Mul<Radix>,
+    Prod<Acc, Radix>: Add<AsUnsigned<V>>,
+    Rest: PrivateFromDigitsMsb<Radix, Sum<Prod<Acc, Radix>, AsUnsigned<V>>>,
+{
+    type Output =
+        <Rest as PrivateFromDigitsMsb<Radix, Sum<Prod<Acc, Radix>, AsUnsigned<V>>>>::Output;
+}
+
+/// `digit + radix * rest`, from the least significant digit.
+#[doc(hidden)]
+pub trait PrivateFromDigitsLsb<Radix> {
+    type Output;
+}
+impl<Radix> PrivateFromDigitsLsb<Radix> for ATerm {
+    type Output = U0;
+}
+impl<V: IntoUnsigned, Rest: PrivateFromDigitsLsb<Radix>, Radix> PrivateFromDigitsLsb<Radix>
+    for TArr<V, Rest>
+where
+    <Rest as PrivateFromDigitsLsb<Radix>>::Output: Mul<Radix>,
+    Prod<<Rest as PrivateFromDigitsLsb<Radix>>::Output, Radix>: Add<AsUnsigned<V>>,
+{
+    type Output = Sum<Prod<<Rest as PrivateFromDigitsLsb<Radix>>::Output, Radix>, AsUnsigned<V>>;
+}
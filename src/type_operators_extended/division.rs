@@ -0,0 +1,314 @@
+//! Division variants.
+//!
+//! typenum's [Div] and [Rem] on [Integer]s truncate toward zero.
+//! This module adds the floor, ceiling and Euclidean variants, for [Integer]s, rational numbers and the cross types.
+//!
+//! For rational numbers, the quotients are [Integer]s and the remainders are rational numbers,
+//! such that `a = q * b + r` always holds.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_type_eq!(Quot<N7, P2>, N3);
+//! assert_type_eq!(DivFloor<N7, P2>, N4);
+//! assert_type_eq!(DivCeil<N7, P2>, N3);
+//! assert_type_eq!(DivEuclid<N7, P2>, N4);
+//!
+//! assert_type_eq!(<N7 as std::ops::Rem<P2>>::Output, N1);
+//! assert_type_eq!(ModFloor<N7, P2>, P1);
+//! assert_type_eq!(RemEuclid<N7, P2>, P1);
+//!
+//! assert_type_eq!(DivRem<N7, P2>, (N3, N1));
+//! ```
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use typenum::{Diff, Integer, NInt, NonZero, PInt, Prod, Quot, Sum, Unsigned, P1, Z0};
+
+use crate::{AsInteger, IntoInteger, ToRational, R};
+
+type Remainder<A, B> = <A as Rem<B>>::Output;
+
+/// Computes the quotient of `Self` by `Rhs`, rounded toward negative infinity.
+///
+/// More convinient syntax with the associated [DivFloor](crate::operator_aliases_extended::DivFloor) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(DivFloor<P7, P2>, P3);
+/// assert_type_eq!(DivFloor<N7, P2>, N4);
+/// assert_type_eq!(DivFloor<P7, N2>, N4);
+/// assert_type_eq!(DivFloor<N7, N2>, P3);
+/// assert_type_eq!(DivFloor<N6, P2>, N3);
+/// assert_type_eq!(DivFloor<Z0, N2>, Z0);
+///
+/// assert_type_eq!(DivFloor<rational!(N7, U2), rational!(P3, U2)>, N3);
+/// assert_type_eq!(DivFloor<CrossInt<N7>, U2>, CrossInt<N4>);
+/// assert_type_eq!(DivFloor<CrossRational<rational!(N1, U2)>, P1>, CrossRational<rational!(N1)>);
+/// ```
+pub trait FloorDiv<Rhs> {
+    /// The quotient.
+    type Output;
+}
+
+/// Computes the remainder of the division of `Self` by `Rhs` rounded toward negative infinity.
+///
+/// The result has the sign of `Rhs`.
+///
+/// More convinient syntax with the associated [ModFloor](crate::operator_aliases_extended::ModFloor) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(ModFloor<P7, P2>, P1);
+/// assert_type_eq!(ModFloor<N7, P2>, P1);
+/// assert_type_eq!(ModFloor<P7, N2>, N1);
+/// assert_type_eq!(ModFloor<N7, N2>, N1);
+/// assert_type_eq!(ModFloor<N6, P2>, Z0);
+///
+/// assert_type_eq!(ModFloor<rational!(N7, U2), rational!(P3, U2)>, rational!(P1));
+/// assert_type_eq!(ModFloor<CrossInt<N7>, U2>, CrossInt<P1>);
+/// ```
+pub trait FloorMod<Rhs> {
+    /// The remainder.
+    type Output;
+}
+
+/// Computes the quotient of `Self` by `Rhs`, rounded toward positive infinity.
+///
+/// More convinient syntax with the associated [DivCeil](crate::operator_aliases_extended::DivCeil) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(DivCeil<P7, P2>, P4);
+/// assert_type_eq!(DivCeil<N7, P2>, N3);
+/// assert_type_eq!(DivCeil<P7, N2>, N3);
+/// assert_type_eq!(DivCeil<N7, N2>, P4);
+/// assert_type_eq!(DivCeil<P6, P2>, P3);
+///
+/// assert_type_eq!(DivCeil<rational!(P7, U2), rational!(P3, U2)>, P3);
+/// assert_type_eq!(DivCeil<CrossInt<P7>, U2>, CrossInt<P4>);
+/// assert_type_eq!(DivCeil<CrossRational<rational!(P7, U2)>, U2>, CrossRational<rational!(P2)>);
+/// ```
+pub trait CeilDiv<Rhs> {
+    /// The quotient.
+    type Output;
+}
+
+/// Computes the quotient of the Euclidean division of `Self` by `Rhs`.
+///
+/// It is the quotient for which the remainder is non-negative: rounded toward negative infinity if `Rhs` is positive,
+/// and toward positive infinity if it is negative.
+///
+/// More convinient syntax with the associated [DivEuclid](crate::operator_aliases_extended::DivEuclid) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(DivEuclid<P7, P2>, P3);
+/// assert_type_eq!(DivEuclid<N7, P2>, N4);
+/// assert_type_eq!(DivEuclid<P7, N2>, N3);
+/// assert_type_eq!(DivEuclid<N7, N2>, P4);
+///
+/// assert_type_eq!(DivEuclid<rational!(N7, U2), rational!(N3, U2)>, P3);
+/// assert_type_eq!(DivEuclid<CrossInt<N7>, N2>, CrossInt<P4>);
+/// assert_type_eq!(DivEuclid<CrossRational<rational!(N7, U2)>, U2>, CrossRational<rational!(N2)>);
+/// ```
+pub trait EuclidDiv<Rhs> {
+    /// The quotient.
+    type Output;
+}
+
+/// Computes the remainder of the Euclidean division of `Self` by `Rhs`.
+///
+/// The result is always non-negative, and less than the absolute value of `Rhs`.
+///
+/// More convinient syntax with the associated [RemEuclid](crate::operator_aliases_extended::RemEuclid) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(RemEuclid<P7, P2>, P1);
+/// assert_type_eq!(RemEuclid<N7, P2>, P1);
+/// assert_type_eq!(RemEuclid<P7, N2>, P1);
+/// assert_type_eq!(RemEuclid<N7, N2>, P1);
+/// assert_type_eq!(RemEuclid<N8, N2>, Z0);
+///
+/// assert_type_eq!(RemEuclid<rational!(N7, U2), rational!(N3, U2)>, rational!(P1));
+/// assert_type_eq!(RemEuclid<CrossInt<N7>, N2>, CrossInt<P1>);
+/// assert_type_eq!(RemEuclid<CrossRational<rational!(N1, U3)>, P1>, CrossRational<rational!(P2, U3)>);
+/// ```
+pub trait EuclidRem<Rhs> {
+    /// The remainder.
+    type Output;
+}
+
+/// Computes both the truncated quotient and the remainder of the division of `Self` by `Rhs`, as a `(Quotient, Remainder)` pair.
+///
+/// For [Integer]s, it is the same as typenum's [Div] and [Rem].
+///
+/// More convinient syntax with the associated [DivRem](crate::operator_aliases_extended::DivRem) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(DivRem<P7, P2>, (P3, P1));
+/// assert_type_eq!(DivRem<N7, P2>, (N3, N1));
+/// assert_type_eq!(DivRem<rational!(N7, U2), rational!(P3, U2)>, (N2, rational!(N1, U2)));
+/// assert_type_eq!(DivRem<CrossInt<N7>, U2>, (CrossInt<N3>, CrossInt<N1>));
+/// assert_type_eq!(DivRem<CrossRational<rational!(N7, U2)>, U1>, (CrossRational<rational!(N3)>, CrossRational<rational!(N1, U2)>));
+/// ```
+pub trait DivAndRem<Rhs> {
+    /// The `(Quotient, Remainder)` pair.
+    type Output;
+}
+
+/// Rounding of a truncated division. Implemented for `(Remainder, Divisor)`, `Q` being the truncated quotient.
+#[doc(hidden)]
+pub trait PrivateRounding<Q> {
+    type FloorQuot;
+    type FloorRem;
+    type CeilQuot;
+    type EuclidQuot;
+    type EuclidRem;
+}
+/// Exact division.
+impl<Q, B> PrivateRounding<Q> for (Z0, B) {
+    type FloorQuot = Q;
+    type FloorRem = Z0;
+    type CeilQuot = Q;
+    type EuclidQuot = Q;
+    type EuclidRem = Z0;
+}
+impl<Q: Add<P1>, Ur: Unsigned + NonZero, Ub: Unsigned + NonZero> PrivateRounding<Q> for (PInt<Ur>, PInt<Ub>) {
+    type FloorQuot = Q;
+    type FloorRem = PInt<Ur>;
+    type CeilQuot = Sum<Q, P1>;
+    type EuclidQuot = Q;
+    type EuclidRem = PInt<Ur>;
+}
+impl<Q: Add<P1>, Ur: Unsigned + NonZero, Ub: Unsigned + NonZero> PrivateRounding<Q> for (NInt<Ur>, NInt<Ub>)
+where
+    NInt<Ur>: Sub<NInt<Ub>>,
+{
+    type FloorQuot = Q;
+    type FloorRem = NInt<Ur>;
+    type CeilQuot = Sum<Q, P1>;
+    type EuclidQuot = Sum<Q, P1>;
+    type EuclidRem = Diff<NInt<Ur>, NInt<Ub>>;
+}
+impl<Q: Sub<P1>, Ur: Unsigned + NonZero, Ub: Unsigned + NonZero> PrivateRounding<Q> for (PInt<Ur>, NInt<Ub>)
+where
+    PInt<Ur>: Add<NInt<Ub>>,
+{
+    type FloorQuot = Diff<Q, P1>;
+    type FloorRem = Sum<PInt<Ur>, NInt<Ub>>;
+    type CeilQuot = Q;
+    type EuclidQuot = Q;
+    type EuclidRem = PInt<Ur>;
+}
+impl<Q: Sub<P1>, Ur: Unsigned + NonZero, Ub: Unsigned + NonZero> PrivateRounding<Q> for (NInt<Ur>, PInt<Ub>)
+where
+    NInt<Ur>: Add<PInt<Ub>>,
+{
+    type FloorQuot = Diff<Q, P1>;
+    type FloorRem = Sum<NInt<Ur>, PInt<Ub>>;
+    type CeilQuot = Q;
+    type EuclidQuot = Diff<Q, P1>;
+    type EuclidRem = Sum<NInt<Ur>, PInt<Ub>>;
+}
+
+macro_rules! impl_integer {
+    ($Trait:ident => $Assoc:ident) => {
+        impl<A: Integer, B: Integer> $Trait<B> for A
+        where
+            A: Div<B> + Rem<B>,
+            (Remainder<A, B>, B): PrivateRounding<Quot<A, B>>,
+        {
+            type Output = <(Remainder<A, B>, B) as PrivateRounding<Quot<A, B>>>::$Assoc;
+        }
+    };
+}
+
+impl_integer! {FloorDiv => FloorQuot}
+impl_integer! {FloorMod => FloorRem}
+impl_integer! {CeilDiv => CeilQuot}
+impl_integer! {EuclidDiv => EuclidQuot}
+impl_integer! {EuclidRem => EuclidRem}
+
+impl<A: Integer, B: Integer> DivAndRem<B> for A
+where
+    A: Div<B> + Rem<B>,
+{
+    type Output = (Quot<A, B>, Remainder<A, B>);
+}
+
+/// `Nl/Dl` divided by `Nr/Dr` is `(Nl * Dr) / (Nr * Dl)`, and `Nr * Dl` has the sign of `Nr/Dr`.
+#[doc(hidden)]
+pub type PrivateCrossNum<Nl, Dr> = Prod<Nl, AsInteger<Dr>>;
+
+macro_rules! impl_rational_quotient {
+    ($Trait:ident) => {
+        impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> $Trait<R<Nr, Dr>> for R<Nl, Dl>
+        where
+            Dl: IntoInteger,
+            Dr: IntoInteger,
+            Nl: Mul<AsInteger<Dr>>,
+            Nr: Mul<AsInteger<Dl>>,
+            PrivateCrossNum<Nl, Dr>: $Trait<PrivateCrossNum<Nr, Dl>>,
+        {
+            type Output = <PrivateCrossNum<Nl, Dr> as $Trait<PrivateCrossNum<Nr, Dl>>>::Output;
+        }
+    };
+}
+
+macro_rules! impl_rational_remainder {
+    ($Trait:ident) => {
+        /// The remainder of `(Nl * Dr) / (Nr * Dl)`, divided by `Dl * Dr`.
+        impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> $Trait<R<Nr, Dr>> for R<Nl, Dl>
+        where
+            Dl: IntoInteger + Mul<Dr>,
+            Dr: IntoInteger,
+            Nl: Mul<AsInteger<Dr>>,
+            Nr: Mul<AsInteger<Dl>>,
+            PrivateCrossNum<Nl, Dr>: $Trait<PrivateCrossNum<Nr, Dl>>,
+            R<<PrivateCrossNum<Nl, Dr> as $Trait<PrivateCrossNum<Nr, Dl>>>::Output, Prod<Dl, Dr>>: crate::Simplify,
+            <PrivateCrossNum<Nl, Dr> as $Trait<PrivateCrossNum<Nr, Dl>>>::Output: Integer,
+            Prod<Dl, Dr>: Unsigned + NonZero,
+        {
+            type Output = ToRational<<PrivateCrossNum<Nl, Dr> as $Trait<PrivateCrossNum<Nr, Dl>>>::Output, Prod<Dl, Dr>>;
+        }
+    };
+}
+
+impl_rational_quotient! {FloorDiv}
+impl_rational_remainder! {FloorMod}
+impl_rational_quotient! {CeilDiv}
+impl_rational_quotient! {EuclidDiv}
+impl_rational_remainder! {EuclidRem}
+
+impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> DivAndRem<R<Nr, Dr>> for R<Nl, Dl>
+where
+    Dl: IntoInteger + Mul<Dr>,
+    Dr: IntoInteger,
+    Nl: Mul<AsInteger<Dr>>,
+    Nr: Mul<AsInteger<Dl>>,
+    PrivateCrossNum<Nl, Dr>: Div<PrivateCrossNum<Nr, Dl>> + Rem<PrivateCrossNum<Nr, Dl>>,
+    R<Remainder<PrivateCrossNum<Nl, Dr>, PrivateCrossNum<Nr, Dl>>, Prod<Dl, Dr>>: crate::Simplify,
+    Remainder<PrivateCrossNum<Nl, Dr>, PrivateCrossNum<Nr, Dl>>: Integer,
+    Prod<Dl, Dr>: Unsigned + NonZero,
+{
+    type Output = (
+        Quot<PrivateCrossNum<Nl, Dr>, PrivateCrossNum<Nr, Dl>>,
+        ToRational<Remainder<PrivateCrossNum<Nl, Dr>, PrivateCrossNum<Nr, Dl>>, Prod<Dl, Dr>>,
+    );
+}
@@ -0,0 +1,183 @@
+//! Fixed-width arithmetic on [Unsigned] numbers.
+//!
+//! Type level arithmetic has infinite precision. The operators of this module model registers of `W` bits instead:
+//! results are truncated to their `W` lowest bits, flagged on overflow, or saturated.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_type_eq!(Truncate<U300, U8>, U44);
+//! assert_type_eq!(WrappingAdd<U200, U100, U8>, U44);
+//! assert_type_eq!(WrappingSub<U3, U5, U8>, U254);
+//! assert_type_eq!(WrappingMul<U20, U20, U8>, U144);
+//! assert_type_eq!(OverflowingAdd<U200, U100, U8>, (U44, True));
+//! assert_type_eq!(SaturatingAdd<U200, U100, U8>, U255);
+//! ```
+
+use std::ops::{Add, Mul, Rem, Shl, Sub};
+
+use typenum::{Diff, IsGreaterOrEqual, GrEq, Min, Minimum, Prod, Shleft, Sub1, Sum, Unsigned, B1, U1};
+
+/// `A` truncated to its `W` lowest bits.
+type PrivateTruncated<A, W> = <A as Rem<Shleft<U1, W>>>::Output;
+
+/// Keeps the `W` lowest bits of an [Unsigned] number.
+///
+/// More convinient syntax with the associated [Truncate](crate::operator_aliases_extended::Truncate) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Truncate<U255, U8>, U255);
+/// assert_type_eq!(Truncate<U256, U8>, U0);
+/// assert_type_eq!(Truncate<U13, U2>, U1);
+/// assert_type_eq!(Truncate<U13, U0>, U0);
+/// ```
+pub trait TruncateBits<W> {
+    /// The truncated number.
+    type Output;
+}
+
+impl<A: Unsigned, W: Unsigned> TruncateBits<W> for A
+where
+    U1: Shl<W>,
+    A: Rem<Shleft<U1, W>>,
+{
+    type Output = PrivateTruncated<A, W>;
+}
+
+/// Adds two [Unsigned] numbers, keeping the `W` lowest bits of the result.
+///
+/// More convinient syntax with the associated [WrappingAdd](crate::operator_aliases_extended::WrappingAdd) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(WrappingAdd<U10, U5, U4>, U15);
+/// assert_type_eq!(WrappingAdd<U10, U6, U4>, U0);
+/// assert_type_eq!(WrappingAdd<U15, U15, U4>, U14);
+/// ```
+pub trait WrapAdd<Rhs, W> {
+    /// The wrapped sum.
+    type Output;
+}
+
+impl<A: Unsigned, B: Unsigned, W: Unsigned> WrapAdd<B, W> for A
+where
+    A: Add<B>,
+    Sum<A, B>: TruncateBits<W>,
+{
+    type Output = <Sum<A, B> as TruncateBits<W>>::Output;
+}
+
+/// Subtracts two [Unsigned] numbers modulo `2^W`.
+///
+/// More convinient syntax with the associated [WrappingSub](crate::operator_aliases_extended::WrappingSub) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(WrappingSub<U10, U5, U4>, U5);
+/// assert_type_eq!(WrappingSub<U0, U1, U4>, U15);
+/// assert_type_eq!(WrappingSub<U5, U5, U4>, U0);
+/// assert_type_eq!(WrappingSub<U5, U20, U4>, U1);
+/// ```
+pub trait WrapSub<Rhs, W> {
+    /// The wrapped difference.
+    type Output;
+}
+
+/// `a - b = a + (2^W - b mod 2^W) mod 2^W`
+impl<A: Unsigned, B: Unsigned, W: Unsigned> WrapSub<B, W> for A
+where
+    U1: Shl<W>,
+    B: Rem<Shleft<U1, W>>,
+    Shleft<U1, W>: Sub<PrivateTruncated<B, W>>,
+    A: Add<Diff<Shleft<U1, W>, PrivateTruncated<B, W>>>,
+    Sum<A, Diff<Shleft<U1, W>, PrivateTruncated<B, W>>>: Rem<Shleft<U1, W>>,
+{
+    type Output = PrivateTruncated<Sum<A, Diff<Shleft<U1, W>, PrivateTruncated<B, W>>>, W>;
+}
+
+/// Multiplies two [Unsigned] numbers, keeping the `W` lowest bits of the result.
+///
+/// More convinient syntax with the associated [WrappingMul](crate::operator_aliases_extended::WrappingMul) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(WrappingMul<U3, U5, U4>, U15);
+/// assert_type_eq!(WrappingMul<U4, U5, U4>, U4);
+/// assert_type_eq!(WrappingMul<U255, U255, U8>, U1);
+/// ```
+pub trait WrapMul<Rhs, W> {
+    /// The wrapped product.
+    type Output;
+}
+
+impl<A: Unsigned, B: Unsigned, W: Unsigned> WrapMul<B, W> for A
+where
+    A: Mul<B>,
+    Prod<A, B>: TruncateBits<W>,
+{
+    type Output = <Prod<A, B> as TruncateBits<W>>::Output;
+}
+
+/// Adds two [Unsigned] numbers on `W` bits, giving the wrapped sum and a [True](typenum::True) or [False](typenum::False) overflow flag,
+/// as a `(Sum, Overflow)` pair.
+///
+/// More convinient syntax with the associated [OverflowingAdd](crate::operator_aliases_extended::OverflowingAdd) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(OverflowingAdd<U10, U5, U4>, (U15, False));
+/// assert_type_eq!(OverflowingAdd<U10, U6, U4>, (U0, True));
+/// assert_type_eq!(OverflowingAdd<U0, U0, U0>, (U0, False));
+/// ```
+pub trait OverflowAdd<Rhs, W> {
+    /// The `(Sum, Overflow)` pair.
+    type Output;
+}
+
+impl<A: Unsigned, B: Unsigned, W: Unsigned> OverflowAdd<B, W> for A
+where
+    U1: Shl<W>,
+    A: Add<B>,
+    Sum<A, B>: Rem<Shleft<U1, W>> + IsGreaterOrEqual<Shleft<U1, W>>,
+{
+    type Output = (PrivateTruncated<Sum<A, B>, W>, GrEq<Sum<A, B>, Shleft<U1, W>>);
+}
+
+/// Adds two [Unsigned] numbers, clamping the result to the largest number on `W` bits: `2^W - 1`.
+///
+/// More convinient syntax with the associated [SaturatingAdd](crate::operator_aliases_extended::SaturatingAdd) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(SaturatingAdd<U10, U5, U4>, U15);
+/// assert_type_eq!(SaturatingAdd<U10, U6, U4>, U15);
+/// assert_type_eq!(SaturatingAdd<U1, U2, U4>, U3);
+/// ```
+pub trait SaturateAdd<Rhs, W> {
+    /// The saturated sum.
+    type Output;
+}
+
+impl<A: Unsigned, B: Unsigned, W: Unsigned> SaturateAdd<B, W> for A
+where
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<B1>,
+    A: Add<B>,
+    Sum<A, B>: Min<Sub1<Shleft<U1, W>>>,
+{
+    type Output = Minimum<Sum<A, B>, Sub1<Shleft<U1, W>>>;
+}
@@ -0,0 +1,179 @@
+use std::ops::{Div, Mul};
+
+use typenum::{ATerm, Gcd, Gcf, Integer, NInt, NonZero, PInt, Prod, Quot, TArr, UInt, UTerm, Unsigned, Z0};
+
+use crate::R;
+
+/// Computes the least common multiple of `Self` and `Rhs`.
+///
+/// The result is never negative, and is zero as soon as one of the operands is zero.
+///
+/// For rational numbers, it is the smallest positive rational which is an integer multiple of both:
+/// the least common multiple of the numerators over the greatest common divisor of the denominators.
+/// [Gcd] is implemented for rational numbers with the matching definition.
+///
+/// More convinient syntax with the associated [LeastCommonMultiple](crate::operator_aliases_extended::LeastCommonMultiple) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(LeastCommonMultiple<U4, U6>, U12);
+/// assert_type_eq!(LeastCommonMultiple<U0, U6>, U0);
+/// assert_type_eq!(LeastCommonMultiple<N4, P6>, P12);
+/// assert_type_eq!(LeastCommonMultiple<Z0, N6>, Z0);
+///
+/// assert_type_eq!(LeastCommonMultiple<rational!(P1, U2), rational!(P2, U3)>, rational!(P2));
+/// assert_type_eq!(LeastCommonMultiple<rational!(P3, U4), rational!(N5, U6)>, rational!(P15, U2));
+/// assert_type_eq!(Gcf<rational!(P3, U4), rational!(N5, U6)>, rational!(P1, U12));
+/// assert_type_eq!(Gcf<rational!(Z0), rational!(N5, U6)>, rational!(P5, U6));
+///
+/// // Common sample rate base of 44.1kHz and 48kHz.
+/// assert_type_eq!(LeastCommonMultiple<CrossInt<Prod<P441, P100>>, Prod<U480, U100>>, CrossInt<Prod<P441, Prod<P16, P1000>>>);
+/// assert_type_eq!(LeastCommonMultiple<CrossRational<rational!(P1, U2)>, U3>, CrossRational<rational!(P3)>);
+/// ```
+pub trait Lcm<Rhs> {
+    /// The least common multiple.
+    type Output;
+}
+
+impl<U: Unsigned> Lcm<U> for UTerm {
+    type Output = UTerm;
+}
+
+impl<U: Unsigned, B> Lcm<UTerm> for UInt<U, B> {
+    type Output = UTerm;
+}
+
+/// `lcm(a, b) = a / gcd(a, b) * b`
+impl<Ul: Unsigned, Bl, Ur: Unsigned, Br> Lcm<UInt<Ur, Br>> for UInt<Ul, Bl>
+where
+    UInt<Ul, Bl>: Gcd<UInt<Ur, Br>>,
+    UInt<Ul, Bl>: Div<Gcf<UInt<Ul, Bl>, UInt<Ur, Br>>>,
+    Quot<UInt<Ul, Bl>, Gcf<UInt<Ul, Bl>, UInt<Ur, Br>>>: Mul<UInt<Ur, Br>>,
+{
+    type Output = Prod<Quot<UInt<Ul, Bl>, Gcf<UInt<Ul, Bl>, UInt<Ur, Br>>>, UInt<Ur, Br>>;
+}
+
+impl<I: Integer> Lcm<I> for Z0 {
+    type Output = Z0;
+}
+
+impl<U: Unsigned + NonZero> Lcm<Z0> for PInt<U> {
+    type Output = Z0;
+}
+
+impl<U: Unsigned + NonZero> Lcm<Z0> for NInt<U> {
+    type Output = Z0;
+}
+
+impl<Ul: Unsigned + NonZero, Ur: Unsigned + NonZero> Lcm<PInt<Ur>> for PInt<Ul>
+where
+    Ul: Lcm<Ur>,
+    <Ul as Lcm<Ur>>::Output: Unsigned + NonZero,
+{
+    type Output = PInt<<Ul as Lcm<Ur>>::Output>;
+}
+
+impl<Ul: Unsigned + NonZero, Ur: Unsigned + NonZero> Lcm<NInt<Ur>> for PInt<Ul>
+where
+    Ul: Lcm<Ur>,
+    <Ul as Lcm<Ur>>::Output: Unsigned + NonZero,
+{
+    type Output = PInt<<Ul as Lcm<Ur>>::Output>;
+}
+
+impl<Ul: Unsigned + NonZero, Ur: Unsigned + NonZero> Lcm<PInt<Ur>> for NInt<Ul>
+where
+    Ul: Lcm<Ur>,
+    <Ul as Lcm<Ur>>::Output: Unsigned + NonZero,
+{
+    type Output = PInt<<Ul as Lcm<Ur>>::Output>;
+}
+
+impl<Ul: Unsigned + NonZero, Ur: Unsigned + NonZero> Lcm<NInt<Ur>> for NInt<Ul>
+where
+    Ul: Lcm<Ur>,
+    <Ul as Lcm<Ur>>::Output: Unsigned + NonZero,
+{
+    type Output = PInt<<Ul as Lcm<Ur>>::Output>;
+}
+
+/// `lcm(a/b, c/d) = lcm(a, c)/gcd(b, d)`, which is already simplified.
+impl<Nl: Integer, Dl: Unsigned + NonZero, Nr: Integer, Dr: Unsigned + NonZero> Lcm<R<Nr, Dr>> for R<Nl, Dl>
+where
+    Nl: Lcm<Nr>,
+    Dl: Gcd<Dr>,
+    <Nl as Lcm<Nr>>::Output: Integer,
+    Gcf<Dl, Dr>: Unsigned + NonZero,
+{
+    type Output = R<<Nl as Lcm<Nr>>::Output, Gcf<Dl, Dr>>;
+}
+
+/// Computes the greatest common divisor of all the elements of a [TArr].
+///
+/// It is not implemented for empty arrays, and a single element gives its magnitude.
+///
+/// More convinient syntax with the associated [FoldGreatestCommonDivisor](crate::operator_aliases_extended::FoldGreatestCommonDivisor) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(FoldGreatestCommonDivisor<tarr![U12, U18, U30]>, U6);
+/// assert_type_eq!(FoldGreatestCommonDivisor<tarr![P12, N18]>, P6);
+/// assert_type_eq!(FoldGreatestCommonDivisor<tarr![rational!(P1, U2), rational!(P3, U4)]>, rational!(P1, U4));
+/// assert_type_eq!(FoldGreatestCommonDivisor<tarr![U7]>, U7);
+/// assert_type_eq!(FoldGreatestCommonDivisor<tarr![N4]>, P4);
+/// ```
+pub trait FoldGcd {
+    /// The greatest common divisor.
+    type Output;
+}
+
+/// `gcd(v, v) = |v|`, as typenum has no [Abs](typenum::Abs) for unsigned numbers.
+impl<V: Gcd<V>> FoldGcd for TArr<V, ATerm> {
+    type Output = Gcf<V, V>;
+}
+
+impl<V, V2, A> FoldGcd for TArr<V, TArr<V2, A>>
+where
+    TArr<V2, A>: FoldGcd,
+    V: Gcd<<TArr<V2, A> as FoldGcd>::Output>,
+{
+    type Output = Gcf<V, <TArr<V2, A> as FoldGcd>::Output>;
+}
+
+/// Computes the least common multiple of all the elements of a [TArr].
+///
+/// It is not implemented for empty arrays, and a single element gives its magnitude.
+///
+/// More convinient syntax with the associated [FoldLeastCommonMultiple](crate::operator_aliases_extended::FoldLeastCommonMultiple) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(FoldLeastCommonMultiple<tarr![U4, U6, U10]>, U60);
+/// assert_type_eq!(FoldLeastCommonMultiple<tarr![N4, P6]>, P12);
+/// assert_type_eq!(FoldLeastCommonMultiple<tarr![rational!(P1, U2), rational!(P1, U3)]>, rational!(P1));
+/// assert_type_eq!(FoldLeastCommonMultiple<tarr![N4]>, P4);
+/// assert_type_eq!(FoldLeastCommonMultiple<tarr![rational!(N1, U2)]>, rational!(P1, U2));
+/// ```
+pub trait FoldLcm {
+    /// The least common multiple.
+    type Output;
+}
+
+/// `lcm(v, v) = |v|`, as typenum has no [Abs](typenum::Abs) for unsigned numbers.
+impl<V: Lcm<V>> FoldLcm for TArr<V, ATerm> {
+    type Output = <V as Lcm<V>>::Output;
+}
+
+impl<V, V2, A> FoldLcm for TArr<V, TArr<V2, A>>
+where
+    TArr<V2, A>: FoldLcm,
+    V: Lcm<<TArr<V2, A> as FoldLcm>::Output>,
+{
+    type Output = <V as Lcm<<TArr<V2, A> as FoldLcm>::Output>>::Output;
+}
@@ -0,0 +1,224 @@
+//! Readable [type_name](std::any::type_name) output, with the typenum numbers replaced by their values.
+//!
+//! Every `UInt`, `PInt`, `NInt`, `Z0`, `R`, `CrossInt` and `CrossRational` subtree of a type name is replaced by
+//! its value, formatted like [TypeDisplay](crate::TypeDisplay) does. The rest of the name is kept as is.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_eq!(pretty_type_name::<Vec<(U2, rational!(N3, U4))>>(), "alloc::vec::Vec<(2, -3/4)>");
+//! assert_eq!(
+//!     prettify_type_name("my_crate::Matrix<typenum::uint::UInt<typenum::uint::UInt<typenum::uint::UTerm, typenum::bit::B1>, typenum::bit::B0>, f64>"),
+//!     "my_crate::Matrix<2, f64>",
+//! );
+//! ```
+
+use super::type_display::number_string;
+
+/// Name of a type, with the typenum numbers replaced by their values.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_eq!(pretty_type_name::<U1024>(), "1024");
+/// assert_eq!(pretty_type_name::<Option<CrossInt<N5>>>(), "core::option::Option<-5>");
+/// assert_eq!(pretty_type_name::<[rational!(P6, U3); 2]>(), "[2; 2]");
+/// assert_eq!(pretty_type_name::<fn(P1) -> Z0>(), "fn(1) -> 0");
+/// assert_eq!(pretty_type_name::<(B1, Shleft<U1, U80>)>(), "(B1, 1208925819614629174706176)");
+/// ```
+pub fn pretty_type_name<T: ?Sized>() -> String {
+    prettify_type_name(std::any::type_name::<T>())
+}
+
+/// Replaces the typenum numbers of a type name, as given by [type_name](std::any::type_name), by their values.
+///
+/// Only the paths starting with `typenum` or `extended_typenum`, or without any module, are replaced.
+/// The latter are found in compiler diagnostics for example.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_eq!(prettify_type_name("typenum::int::NInt<typenum::uint::UInt<typenum::uint::UTerm, typenum::bit::B1>>"), "-1");
+/// assert_eq!(prettify_type_name("extended_typenum::rational::R<typenum::int::Z0, typenum::uint::UInt<typenum::uint::UTerm, typenum::bit::B1>>"), "0");
+/// assert_eq!(prettify_type_name("my_crate::UInt<u8, u8>"), "my_crate::UInt<u8, u8>");
+/// assert_eq!(prettify_type_name("expected `UInt<UInt<UTerm, B1>, B0>`, found `UInt<UInt<UTerm, B1>, _>`"), "expected `2`, found `UInt<UInt<UTerm, B1>, _>`");
+/// ```
+pub fn prettify_type_name(name: &str) -> String {
+    let mut parser = Parser { chars: name.chars().collect(), pos: 0 };
+    let mut result = String::new();
+    while parser.pos < parser.chars.len() {
+        result.push_str(&parser.parse_argument().text);
+        // Unbalanced closing delimiter.
+        if let Some(c) = parser.bump() {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Value of a typenum subtree, the numbers are given by their bits, most significant first.
+#[derive(Clone)]
+enum Value {
+    Bit(bool),
+    Unsigned(Vec<bool>),
+    Integer(bool, Vec<bool>),
+    Rational(bool, Vec<bool>, Vec<bool>),
+}
+
+impl Value {
+    /// Name of the typenum type of a path, if it is one.
+    fn typenum_name(path: &str) -> Option<&str> {
+        let (root, name) = match path.rsplit_once("::") {
+            Some((prefix, name)) => (prefix.split("::").next()?, name),
+            None => ("typenum", path),
+        };
+        let known = ["B0", "B1", "UTerm", "UInt", "Z0", "PInt", "NInt", "R", "CrossInt", "CrossRational"];
+        ((root == "typenum" || root == "extended_typenum") && known.contains(&name)).then_some(name)
+    }
+
+    /// Bits are accumulated like the `PrivateMagnitude` of the types does.
+    fn evaluate(name: &str, arguments: &[Option<Value>]) -> Option<Value> {
+        match (name, arguments) {
+            ("B0", []) => Some(Value::Bit(false)),
+            ("B1", []) => Some(Value::Bit(true)),
+            ("UTerm", []) => Some(Value::Unsigned(Vec::new())),
+            ("UInt", [Some(Value::Unsigned(u)), Some(Value::Bit(b))]) => {
+                let mut bits = u.clone();
+                bits.push(*b);
+                Some(Value::Unsigned(bits))
+            }
+            ("Z0", []) => Some(Value::Integer(false, Vec::new())),
+            ("PInt", [Some(Value::Unsigned(u))]) => Some(Value::Integer(false, u.clone())),
+            ("NInt", [Some(Value::Unsigned(u))]) => Some(Value::Integer(true, u.clone())),
+            ("R", [Some(Value::Integer(negative, n)), Some(Value::Unsigned(d))]) => {
+                Some(Value::Rational(*negative, n.clone(), d.clone()))
+            }
+            ("CrossInt" | "CrossRational", [Some(value)]) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    fn text(&self) -> String {
+        match self {
+            Value::Bit(false) => "B0".to_string(),
+            Value::Bit(true) => "B1".to_string(),
+            Value::Unsigned(u) => number_string(false, u, &[true]),
+            Value::Integer(negative, u) => number_string(*negative, u, &[true]),
+            Value::Rational(negative, n, d) => number_string(*negative, n, d),
+        }
+    }
+}
+
+/// A rewritten generic argument, with its value if it is a single typenum subtree.
+struct Argument {
+    text: String,
+    value: Option<Value>,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += c.is_some() as usize;
+        c
+    }
+
+    /// Parses until a top-level separator or closing delimiter, which is not consumed.
+    fn parse_argument(&mut self) -> Argument {
+        let mut text = String::new();
+        let mut value = None;
+        let mut items = 0;
+        while let Some(c) = self.peek() {
+            match c {
+                ',' | ';' | '>' | ')' | ']' => break,
+                '-' if self.chars.get(self.pos + 1) == Some(&'>') => {
+                    self.pos += 2;
+                    text.push_str("->");
+                    items += 1;
+                }
+                '(' | '[' => {
+                    self.pos += 1;
+                    text.push(c);
+                    let close = if c == '(' { ')' } else { ']' };
+                    loop {
+                        text.push_str(&self.parse_argument().text);
+                        match self.bump() {
+                            Some(d) if d == close => {
+                                text.push(d);
+                                break;
+                            }
+                            Some(d) => text.push(d),
+                            None => break,
+                        }
+                    }
+                    items += 1;
+                }
+                c if c.is_alphanumeric() || c == '_' => {
+                    let (path_text, path_value) = self.parse_path();
+                    text.push_str(&path_text);
+                    value = path_value;
+                    items += 1;
+                }
+                c => {
+                    self.pos += 1;
+                    text.push(c);
+                    items += !c.is_whitespace() as usize;
+                }
+            }
+        }
+        Argument { text, value: if items == 1 { value } else { None } }
+    }
+
+    /// Parses a path and its generic arguments.
+    ///
+    /// Typenum types that can't be evaluated, like `UInt<_, B1>`, are kept as is, arguments included.
+    fn parse_path(&mut self) -> (String, Option<Value>) {
+        let start = self.pos;
+        let mut path = String::new();
+        while let Some(c) = self.peek().filter(|&c| c.is_alphanumeric() || c == '_' || c == ':') {
+            self.pos += 1;
+            path.push(c);
+        }
+        let mut arguments = Vec::new();
+        let mut text = path.clone();
+        if self.peek() == Some('<') {
+            self.pos += 1;
+            text.push('<');
+            loop {
+                let argument = self.parse_argument();
+                text.push_str(&argument.text);
+                arguments.push(argument.value);
+                match self.peek() {
+                    Some(',') => {
+                        self.pos += 1;
+                        text.push(',');
+                    }
+                    Some('>') => {
+                        self.pos += 1;
+                        text.push('>');
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        match Value::typenum_name(&path) {
+            Some(name) => match Value::evaluate(name, &arguments) {
+                Some(value) => (value.text(), Some(value)),
+                None => (self.chars[start..self.pos].iter().collect(), None),
+            },
+            None => (text, None),
+        }
+    }
+}
@@ -0,0 +1,537 @@
+//! Prime numbers, factorization and divisor functions.
+//!
+//! Everything is computed by trial division, so the compilation time grows with the square root of the numbers.
+//! Big numbers may require raising the crate's `recursion_limit`.
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use typenum::{
+    ATerm, Bit, Cmp, Compare, Equal, False, Greater, IsEqual, Less, NInt, NonZero, PInt, Pow, Prod, Quot, Sum,
+    TArr, True, UInt, UTerm, Unsigned, B1, U1, U2, Z0,
+};
+
+use crate::{AsInteger, IntoInteger, IsZero, R};
+
+/// Checks if a number is prime.
+///
+/// The output is either [True] or [False]. Rational numbers are prime if they are prime integers.
+///
+/// More convinient syntax with the associated [IsPrime](crate::operator_aliases_extended::IsPrime) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(IsPrime<U0>, False);
+/// assert_type_eq!(IsPrime<U1>, False);
+/// assert_type_eq!(IsPrime<U2>, True);
+/// assert_type_eq!(IsPrime<U97>, True);
+/// assert_type_eq!(IsPrime<U91>, False);
+/// assert_type_eq!(IsPrime<rational!(P7)>, True);
+/// assert_type_eq!(IsPrime<rational!(P7, U2)>, False);
+/// ```
+pub trait TestPrime {
+    /// [True] if `Self` is prime, [False] otherwise.
+    type Output;
+}
+
+impl TestPrime for UTerm {
+    type Output = False;
+}
+
+impl TestPrime for U1 {
+    type Output = False;
+}
+
+impl<U: Unsigned, Ba: Bit, Bb: Bit> TestPrime for UInt<UInt<U, Ba>, Bb>
+where
+    UInt<UInt<U, Ba>, Bb>: Factorization,
+    <UInt<UInt<U, Ba>, Bb> as Factorization>::Output: PrivateFirstFactor,
+    <<UInt<UInt<U, Ba>, Bb> as Factorization>::Output as PrivateFirstFactor>::Output: IsEqual<UInt<UInt<U, Ba>, Bb>>,
+{
+    type Output = <<<UInt<UInt<U, Ba>, Bb> as Factorization>::Output as PrivateFirstFactor>::Output as IsEqual<
+        UInt<UInt<U, Ba>, Bb>,
+    >>::Output;
+}
+
+impl<D: Unsigned + NonZero> TestPrime for R<Z0, D> {
+    type Output = False;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> TestPrime for R<NInt<N>, D> {
+    type Output = False;
+}
+
+impl<N: Unsigned + NonZero + TestPrime> TestPrime for R<PInt<N>, U1> {
+    type Output = <N as TestPrime>::Output;
+}
+
+impl<N: Unsigned + NonZero, U: Unsigned, Ba: Bit, Bb: Bit> TestPrime for R<PInt<N>, UInt<UInt<U, Ba>, Bb>> {
+    type Output = False;
+}
+
+/// Smallest prime factor from a factorization.
+#[doc(hidden)]
+pub trait PrivateFirstFactor {
+    type Output;
+}
+impl<P, E, A> PrivateFirstFactor for TArr<(P, E), A> {
+    type Output = P;
+}
+
+/// Prime factorization of a number.
+///
+/// The output is a [TArr] of `(prime, exponent)` pairs, sorted by increasing primes.
+/// The factorization of `1` is empty, and `0` has no factorization.
+///
+/// For a positive rational number, the exponents are [Integer](crate::Integer)s: the primes of the denominator have negative exponents.
+///
+/// More convinient syntax with the associated [Factorize](crate::operator_aliases_extended::Factorize) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Factorize<U1>, tarr![]);
+/// assert_type_eq!(Factorize<U2>, tarr![(U2, U1)]);
+/// assert_type_eq!(Factorize<U360>, tarr![(U2, U3), (U3, U2), (U5, U1)]);
+/// assert_type_eq!(Factorize<U1024>, tarr![(U2, U10)]);
+/// assert_type_eq!(Factorize<U1001>, tarr![(U7, U1), (U11, U1), (U13, U1)]);
+///
+/// assert_type_eq!(Factorize<rational!(P9, U20)>, tarr![(U2, N2), (U3, P2), (U5, N1)]);
+/// ```
+pub trait Factorization {
+    /// The factorization of `Self`.
+    type Output;
+}
+
+impl<U: Unsigned, B> Factorization for UInt<U, B>
+where
+    (UInt<U, B>, U2, U1): PrivateFactorize,
+{
+    type Output = <(UInt<U, B>, U2, U1) as PrivateFactorize>::Output;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero> Factorization for R<PInt<N>, D>
+where
+    N: Factorization,
+    D: Factorization,
+    <N as Factorization>::Output: PrivateSignExponents<True>,
+    <D as Factorization>::Output: PrivateSignExponents<False>,
+    <<N as Factorization>::Output as PrivateSignExponents<True>>::Output:
+        PrivateMergeFactors<<<D as Factorization>::Output as PrivateSignExponents<False>>::Output>,
+{
+    type Output = <<<N as Factorization>::Output as PrivateSignExponents<True>>::Output as PrivateMergeFactors<
+        <<D as Factorization>::Output as PrivateSignExponents<False>>::Output,
+    >>::Output;
+}
+
+/// Trial division. The state is `(N, P, Step)` where `N` is what remains to be factored,
+/// `P` the next candidate and `Step` the distance to the following one.
+#[doc(hidden)]
+pub trait PrivateFactorize {
+    type Output;
+}
+impl<N, P, S> PrivateFactorize for (N, P, S)
+where
+    P: Mul<P>,
+    Prod<P, P>: Cmp<N>,
+    (N, P, S): PrivateFactorizeCmp<Compare<Prod<P, P>, N>>,
+{
+    type Output = <(N, P, S) as PrivateFactorizeCmp<Compare<Prod<P, P>, N>>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateFactorizeCmp<Cmp> {
+    type Output;
+}
+/// No candidate left: what remains is either `1` or a prime.
+impl<N, P, S> PrivateFactorizeCmp<Greater> for (N, P, S)
+where
+    N: IsEqual<U1>,
+    N: PrivateLastFactor<<N as IsEqual<U1>>::Output>,
+{
+    type Output = <N as PrivateLastFactor<<N as IsEqual<U1>>::Output>>::Output;
+}
+impl<N, P, S> PrivateFactorizeCmp<Equal> for (N, P, S)
+where
+    (N, P, S): PrivateFactorizeCmp<Less>,
+{
+    type Output = <(N, P, S) as PrivateFactorizeCmp<Less>>::Output;
+}
+impl<N, P, S> PrivateFactorizeCmp<Less> for (N, P, S)
+where
+    N: Rem<P>,
+    <N as Rem<P>>::Output: IsZero,
+    (N, P, S): PrivateFactorizeDiv<<<N as Rem<P>>::Output as IsZero>::Output>,
+{
+    type Output = <(N, P, S) as PrivateFactorizeDiv<<<N as Rem<P>>::Output as IsZero>::Output>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateLastFactor<IsOne> {
+    type Output;
+}
+impl<N> PrivateLastFactor<True> for N {
+    type Output = ATerm;
+}
+impl<N> PrivateLastFactor<False> for N {
+    type Output = TArr<(N, U1), ATerm>;
+}
+
+#[doc(hidden)]
+pub trait PrivateFactorizeDiv<Divides> {
+    type Output;
+}
+impl<N, P, S> PrivateFactorizeDiv<False> for (N, P, S)
+where
+    P: Add<S>,
+    (N, Sum<P, S>, U2): PrivateFactorize,
+{
+    type Output = <(N, Sum<P, S>, U2) as PrivateFactorize>::Output;
+}
+impl<N, P, S> PrivateFactorizeDiv<True> for (N, P, S)
+where
+    N: PrivateStrip<P, UTerm>,
+    P: Add<S>,
+    (<N as PrivateStrip<P, UTerm>>::Rest, Sum<P, S>, U2): PrivateFactorize,
+{
+    type Output = TArr<
+        (P, <N as PrivateStrip<P, UTerm>>::Count),
+        <(<N as PrivateStrip<P, UTerm>>::Rest, Sum<P, S>, U2) as PrivateFactorize>::Output,
+    >;
+}
+
+/// Divides by `P` as long as possible, counting the number of divisions from `Count`.
+#[doc(hidden)]
+pub trait PrivateStrip<P, Count> {
+    type Count;
+    type Rest;
+}
+impl<N, P, Count> PrivateStrip<P, Count> for N
+where
+    N: Rem<P>,
+    <N as Rem<P>>::Output: IsZero,
+    N: PrivateStripStep<P, Count, <<N as Rem<P>>::Output as IsZero>::Output>,
+{
+    type Count = <N as PrivateStripStep<P, Count, <<N as Rem<P>>::Output as IsZero>::Output>>::Count;
+    type Rest = <N as PrivateStripStep<P, Count, <<N as Rem<P>>::Output as IsZero>::Output>>::Rest;
+}
+
+#[doc(hidden)]
+pub trait PrivateStripStep<P, Count, Divides> {
+    type Count;
+    type Rest;
+}
+impl<N, P, Count> PrivateStripStep<P, Count, False> for N {
+    type Count = Count;
+    type Rest = N;
+}
+impl<N, P, Count> PrivateStripStep<P, Count, True> for N
+where
+    N: Div<P>,
+    Count: Add<B1>,
+    Quot<N, P>: PrivateStrip<P, Sum<Count, B1>>,
+{
+    type Count = <Quot<N, P> as PrivateStrip<P, Sum<Count, B1>>>::Count;
+    type Rest = <Quot<N, P> as PrivateStrip<P, Sum<Count, B1>>>::Rest;
+}
+
+/// Converts the exponents of a factorization into positive ([True]) or negative ([False]) [Integer](crate::Integer)s.
+#[doc(hidden)]
+pub trait PrivateSignExponents<Positive> {
+    type Output;
+}
+impl<Positive> PrivateSignExponents<Positive> for ATerm {
+    type Output = ATerm;
+}
+impl<P, E: Unsigned + NonZero, A: PrivateSignExponents<True>> PrivateSignExponents<True> for TArr<(P, E), A> {
+    type Output = TArr<(P, PInt<E>), <A as PrivateSignExponents<True>>::Output>;
+}
+impl<P, E: Unsigned + NonZero, A: PrivateSignExponents<False>> PrivateSignExponents<False> for TArr<(P, E), A> {
+    type Output = TArr<(P, NInt<E>), <A as PrivateSignExponents<False>>::Output>;
+}
+
+/// Merges two factorizations with distinct primes.
+#[doc(hidden)]
+pub trait PrivateMergeFactors<Rhs> {
+    type Output;
+}
+impl<Rhs> PrivateMergeFactors<Rhs> for ATerm {
+    type Output = Rhs;
+}
+impl<P, E, A> PrivateMergeFactors<ATerm> for TArr<(P, E), A> {
+    type Output = TArr<(P, E), A>;
+}
+impl<Pl, El, Al, Pr, Er, Ar> PrivateMergeFactors<TArr<(Pr, Er), Ar>> for TArr<(Pl, El), Al>
+where
+    Pl: Cmp<Pr>,
+    TArr<(Pl, El), Al>: PrivateMergeFactorsCmp<TArr<(Pr, Er), Ar>, Compare<Pl, Pr>>,
+{
+    type Output = <TArr<(Pl, El), Al> as PrivateMergeFactorsCmp<TArr<(Pr, Er), Ar>, Compare<Pl, Pr>>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateMergeFactorsCmp<Rhs, Cmp> {
+    type Output;
+}
+impl<Pl, El, Al, Pr, Er, Ar> PrivateMergeFactorsCmp<TArr<(Pr, Er), Ar>, Less> for TArr<(Pl, El), Al>
+where
+    Al: PrivateMergeFactors<TArr<(Pr, Er), Ar>>,
+{
+    type Output = TArr<(Pl, El), <Al as PrivateMergeFactors<TArr<(Pr, Er), Ar>>>::Output>;
+}
+impl<Pl, El, Al, Pr, Er, Ar> PrivateMergeFactorsCmp<TArr<(Pr, Er), Ar>, Greater> for TArr<(Pl, El), Al>
+where
+    TArr<(Pl, El), Al>: PrivateMergeFactors<Ar>,
+{
+    type Output = TArr<(Pr, Er), <TArr<(Pl, El), Al> as PrivateMergeFactors<Ar>>::Output>;
+}
+
+/// Sorted list of the divisors of a positive number.
+///
+/// Integral positive rational numbers have the divisors of their numerator.
+///
+/// More convinient syntax with the associated [Divisors](crate::operator_aliases_extended::Divisors) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Divisors<U1>, tarr![U1]);
+/// assert_type_eq!(Divisors<U12>, tarr![U1, U2, U3, U4, U6, U12]);
+/// assert_type_eq!(Divisors<U16>, tarr![U1, U2, U4, U8, U16]);
+/// assert_type_eq!(Divisors<U13>, tarr![U1, U13]);
+/// assert_type_eq!(Divisors<rational!(P6)>, tarr![U1, U2, U3, U6]);
+/// ```
+pub trait GetDivisors {
+    /// The divisors of `Self`.
+    type Output;
+}
+
+impl<U: Unsigned, B> GetDivisors for UInt<U, B>
+where
+    (UInt<U, B>, U1, ATerm): PrivateDivisors,
+{
+    type Output = <(UInt<U, B>, U1, ATerm) as PrivateDivisors>::Output;
+}
+
+impl<N: Unsigned + NonZero + GetDivisors> GetDivisors for R<PInt<N>, U1> {
+    type Output = <N as GetDivisors>::Output;
+}
+
+/// Divisors of `N` from `D` onwards. `Acc` holds the big divisors `N/d` found so far, in increasing order.
+#[doc(hidden)]
+pub trait PrivateDivisors {
+    type Output;
+}
+impl<N, D, Acc> PrivateDivisors for (N, D, Acc)
+where
+    D: Mul<D>,
+    Prod<D, D>: Cmp<N>,
+    (N, D, Acc): PrivateDivisorsCmp<Compare<Prod<D, D>, N>>,
+{
+    type Output = <(N, D, Acc) as PrivateDivisorsCmp<Compare<Prod<D, D>, N>>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateDivisorsCmp<Cmp> {
+    type Output;
+}
+impl<N, D, Acc> PrivateDivisorsCmp<Greater> for (N, D, Acc) {
+    type Output = Acc;
+}
+impl<N, D, Acc> PrivateDivisorsCmp<Equal> for (N, D, Acc) {
+    type Output = TArr<D, Acc>;
+}
+impl<N, D, Acc> PrivateDivisorsCmp<Less> for (N, D, Acc)
+where
+    N: Rem<D>,
+    <N as Rem<D>>::Output: IsZero,
+    (N, D, Acc): PrivateDivisorsDiv<<<N as Rem<D>>::Output as IsZero>::Output>,
+{
+    type Output = <(N, D, Acc) as PrivateDivisorsDiv<<<N as Rem<D>>::Output as IsZero>::Output>>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateDivisorsDiv<Divides> {
+    type Output;
+}
+impl<N, D, Acc> PrivateDivisorsDiv<False> for (N, D, Acc)
+where
+    D: Add<B1>,
+    (N, Sum<D, B1>, Acc): PrivateDivisors,
+{
+    type Output = <(N, Sum<D, B1>, Acc) as PrivateDivisors>::Output;
+}
+impl<N, D, Acc> PrivateDivisorsDiv<True> for (N, D, Acc)
+where
+    D: Add<B1>,
+    N: Div<D>,
+    (N, Sum<D, B1>, TArr<Quot<N, D>, Acc>): PrivateDivisors,
+{
+    type Output = TArr<D, <(N, Sum<D, B1>, TArr<Quot<N, D>, Acc>) as PrivateDivisors>::Output>;
+}
+
+/// Euler's totient function: the number of integers in `1..=N` which are coprime with `N`.
+///
+/// Integral positive rational numbers have the totient of their numerator.
+///
+/// More convinient syntax with the associated [Totient](crate::operator_aliases_extended::Totient) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Totient<U1>, U1);
+/// assert_type_eq!(Totient<U12>, U4);
+/// assert_type_eq!(Totient<U13>, U12);
+/// assert_type_eq!(Totient<U360>, U96);
+/// assert_type_eq!(Totient<rational!(P12)>, U4);
+/// ```
+pub trait EulerTotient {
+    /// The totient of `Self`.
+    type Output;
+}
+
+impl<U: Unsigned, B> EulerTotient for UInt<U, B>
+where
+    UInt<U, B>: Factorization,
+    <UInt<U, B> as Factorization>::Output: PrivateTotient,
+{
+    type Output = <<UInt<U, B> as Factorization>::Output as PrivateTotient>::Output;
+}
+
+impl<N: Unsigned + NonZero + EulerTotient> EulerTotient for R<PInt<N>, U1> {
+    type Output = <N as EulerTotient>::Output;
+}
+
+/// Product of `P^(E - 1) * (P - 1)` over the factorization.
+#[doc(hidden)]
+pub trait PrivateTotient {
+    type Output;
+}
+impl PrivateTotient for ATerm {
+    type Output = U1;
+}
+impl<P, E, A> PrivateTotient for TArr<(P, E), A>
+where
+    A: PrivateTotient,
+    E: Sub<B1>,
+    P: Pow<<E as Sub<B1>>::Output> + Sub<B1>,
+    <P as Pow<<E as Sub<B1>>::Output>>::Output: Mul<<P as Sub<B1>>::Output>,
+    Prod<<P as Pow<<E as Sub<B1>>::Output>>::Output, <P as Sub<B1>>::Output>: Mul<<A as PrivateTotient>::Output>,
+{
+    type Output =
+        Prod<Prod<<P as Pow<<E as Sub<B1>>::Output>>::Output, <P as Sub<B1>>::Output>, <A as PrivateTotient>::Output>;
+}
+
+/// Radical of a positive number: the product of its distinct prime factors.
+///
+/// Integral positive rational numbers have the radical of their numerator.
+///
+/// More convinient syntax with the associated [Radical](crate::operator_aliases_extended::Radical) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Radical<U1>, U1);
+/// assert_type_eq!(Radical<U360>, U30);
+/// assert_type_eq!(Radical<U1024>, U2);
+/// assert_type_eq!(Radical<rational!(P360)>, U30);
+/// ```
+pub trait GetRadical {
+    /// The radical of `Self`.
+    type Output;
+}
+
+impl<U: Unsigned, B> GetRadical for UInt<U, B>
+where
+    UInt<U, B>: Factorization,
+    <UInt<U, B> as Factorization>::Output: PrivateRadical,
+{
+    type Output = <<UInt<U, B> as Factorization>::Output as PrivateRadical>::Output;
+}
+
+impl<N: Unsigned + NonZero + GetRadical> GetRadical for R<PInt<N>, U1> {
+    type Output = <N as GetRadical>::Output;
+}
+
+#[doc(hidden)]
+pub trait PrivateRadical {
+    type Output;
+}
+impl PrivateRadical for ATerm {
+    type Output = U1;
+}
+impl<P, E, A> PrivateRadical for TArr<(P, E), A>
+where
+    A: PrivateRadical,
+    P: Mul<<A as PrivateRadical>::Output>,
+{
+    type Output = Prod<P, <A as PrivateRadical>::Output>;
+}
+
+/// `P`-adic valuation: the exponent of the prime `P` in the factorization of a non zero number.
+///
+/// The output is [Unsigned] for [Unsigned] numbers, and an [Integer](crate::Integer) for rational numbers.
+/// `P` must be greater than `1`.
+///
+/// More convinient syntax with the associated [Valuation](crate::operator_aliases_extended::Valuation) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(Valuation<U2, U48>, U4);
+/// assert_type_eq!(Valuation<U3, U48>, U1);
+/// assert_type_eq!(Valuation<U5, U48>, U0);
+/// assert_type_eq!(Valuation<U2, rational!(P3, U8)>, N3);
+/// assert_type_eq!(Valuation<U3, rational!(N9, U8)>, P2);
+/// assert_type_eq!(Valuation<U5, rational!(P9, U8)>, Z0);
+/// // type Fail = Valuation<U1, U48>; `1` divides everything.
+/// ```
+pub trait PAdicValuation<P> {
+    /// The valuation of `Self`.
+    type Output;
+}
+
+impl<U: Unsigned, B, P> PAdicValuation<P> for UInt<U, B>
+where
+    P: PrivateValuation<UInt<U, B>>,
+{
+    type Output = <P as PrivateValuation<UInt<U, B>>>::Output;
+}
+
+/// Valuation of `N`, only implemented for `Self` greater than `1`, as dividing by `1` never stops.
+#[doc(hidden)]
+pub trait PrivateValuation<N> {
+    type Output;
+}
+impl<N, U: Unsigned, Ba: Bit, Bb: Bit> PrivateValuation<N> for UInt<UInt<U, Ba>, Bb>
+where
+    N: PrivateStrip<Self, UTerm>,
+{
+    type Output = <N as PrivateStrip<Self, UTerm>>::Count;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero, P> PAdicValuation<P> for R<PInt<N>, D>
+where
+    N: PAdicValuation<P>,
+    D: PAdicValuation<P>,
+    <N as PAdicValuation<P>>::Output: IntoInteger,
+    <D as PAdicValuation<P>>::Output: IntoInteger,
+    AsInteger<<N as PAdicValuation<P>>::Output>: Sub<AsInteger<<D as PAdicValuation<P>>::Output>>,
+{
+    type Output = <AsInteger<<N as PAdicValuation<P>>::Output> as Sub<
+        AsInteger<<D as PAdicValuation<P>>::Output>,
+    >>::Output;
+}
+
+impl<N: Unsigned + NonZero, D: Unsigned + NonZero, P> PAdicValuation<P> for R<NInt<N>, D>
+where
+    R<PInt<N>, D>: PAdicValuation<P>,
+{
+    type Output = <R<PInt<N>, D> as PAdicValuation<P>>::Output;
+}
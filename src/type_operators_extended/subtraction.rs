@@ -0,0 +1,115 @@
+//! Subtraction of [Unsigned] numbers that may underflow.
+//!
+//! typenum's [Sub] on [Unsigned] numbers is not implemented when the result would be negative.
+//! The operators of this module are always implemented.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! // type Fail = Diff<U3, U5>; Underflow.
+//! assert_type_eq!(SignedSub<U3, U5>, N2);
+//! assert_type_eq!(SaturatingSub<U3, U5>, U0);
+//! assert_type_eq!(CheckedSub<U3, U5>, (U0, False));
+//! assert_type_eq!(CheckedSub<U5, U3>, (U2, True));
+//!
+//! // CrossInt uses signed subtraction.
+//! assert_type_eq!(Diff<CrossInt<U3>, U5>, CrossInt<N2>);
+//! ```
+
+use std::ops::Sub;
+
+use typenum::{Cmp, Compare, Diff, Equal, GrEq, Greater, IsGreaterOrEqual, Less, Unsigned, U0};
+
+use crate::{AsInteger, IntoInteger};
+
+/// Subtracts two numbers after converting them with [IntoInteger], so that the result can be negative.
+///
+/// More convinient syntax with the associated [SignedSub](crate::operator_aliases_extended::SignedSub) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(SignedSub<U5, U3>, P2);
+/// assert_type_eq!(SignedSub<U3, U5>, N2);
+/// assert_type_eq!(SignedSub<U3, U3>, Z0);
+/// assert_type_eq!(SignedSub<U3, N5>, P8);
+/// assert_type_eq!(SignedSub<B0, B1>, N1);
+/// ```
+pub trait SignedSubtract<Rhs> {
+    /// The difference, as an [Integer](typenum::Integer).
+    type Output;
+}
+
+impl<A: IntoInteger, B: IntoInteger> SignedSubtract<B> for A
+where
+    AsInteger<A>: Sub<AsInteger<B>>,
+{
+    type Output = Diff<AsInteger<A>, AsInteger<B>>;
+}
+
+/// Subtracts two [Unsigned] numbers, clamping the result to [U0] instead of underflowing.
+///
+/// More convinient syntax with the associated [SaturatingSub](crate::operator_aliases_extended::SaturatingSub) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(SaturatingSub<U5, U3>, U2);
+/// assert_type_eq!(SaturatingSub<U3, U3>, U0);
+/// assert_type_eq!(SaturatingSub<U3, U5>, U0);
+/// ```
+pub trait SaturateSub<Rhs> {
+    /// The saturated difference.
+    type Output;
+}
+
+impl<A: Unsigned, B: Unsigned> SaturateSub<B> for A
+where
+    A: Cmp<B> + PrivateSaturateSub<B, Compare<A, B>>,
+{
+    type Output = <A as PrivateSaturateSub<B, Compare<A, B>>>::Output;
+}
+
+/// Subtracts two [Unsigned] numbers, giving a `(Difference, Valid)` pair.
+///
+/// `Valid` is [True](typenum::True) if the subtraction doesn't underflow, in which case `Difference` is the difference.
+/// Otherwise, `Valid` is [False](typenum::False) and `Difference` is [U0].
+///
+/// More convinient syntax with the associated [CheckedSub](crate::operator_aliases_extended::CheckedSub) type operator alias.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_type_eq!(CheckedSub<U5, U3>, (U2, True));
+/// assert_type_eq!(CheckedSub<U3, U3>, (U0, True));
+/// assert_type_eq!(CheckedSub<U3, U5>, (U0, False));
+/// ```
+pub trait CheckSub<Rhs> {
+    /// The `(Difference, Valid)` pair.
+    type Output;
+}
+
+impl<A: Unsigned, B: Unsigned> CheckSub<B> for A
+where
+    A: SaturateSub<B> + IsGreaterOrEqual<B>,
+{
+    type Output = (<A as SaturateSub<B>>::Output, GrEq<A, B>);
+}
+
+#[doc(hidden)]
+pub trait PrivateSaturateSub<Rhs, Cmp> {
+    type Output;
+}
+impl<A, B> PrivateSaturateSub<B, Less> for A {
+    type Output = U0;
+}
+impl<A, B> PrivateSaturateSub<B, Equal> for A {
+    type Output = U0;
+}
+impl<A: Sub<B>, B> PrivateSaturateSub<B, Greater> for A {
+    type Output = Diff<A, B>;
+}
@@ -1,11 +1,164 @@
-use typenum::{Integer, NInt, NonZero, PInt, UInt, UTerm, Unsigned, B0, B1, Z0};
+//! Formatting of types, through the [TypeDisplay] trait.
+//!
+//! [DisplayType] wraps a type into a zero-sized value implementing [Display], and [type_string] formats it into a [String].
+//! [True](typenum::True) and [False](typenum::False) are aliases of [B1] and [B0], and are formatted as such.
+//!
+//! The wrappers honour the formatter flags:
+//! - `{:x}`, `{:X}`, `{:b}` and `{:o}` format the numbers in hexadecimal, binary or octal.
+//! - `{:#}` formats the raw typenum structure.
+//! - `{:.5}` formats the rationals as decimals with the given precision, rounded half away from zero.
+//!
+//! Mixed numbers, LaTeX fractions and repeating decimals are enabled with a [TypeFormat], see [DisplayType::with].
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! assert_eq!(type_string::<Sum<U2, U3>>(), "5");
+//! assert_eq!(type_string::<rational!(N3, U4)>(), "-3/4");
+//! assert_eq!(type_string::<rational!(P6, U2)>(), "3");
+//! assert_eq!(type_string::<Compare<U2, U3>>(), "Less");
+//! assert_eq!(type_string::<tarr![U1, N2, P3]>(), "[1, -2, 3]");
+//! assert_eq!(type_string::<(U1, (B1, Z0))>(), "(1, (B1, 0))");
+//! assert_eq!(format!("{}", DisplayType::<Prod<P2, N3>>::new()), "-6");
+//!
+//! // Numbers beyond `usize` are supported.
+//! assert_eq!(type_string::<Shleft<U1, U100>>(), "1267650600228229401496703205376");
+//! assert_eq!(type_string::<NInt<Shleft<U3, U70>>>(), "-3541774862152233910272");
+//! assert_eq!(type_string::<R<N1, Shleft<U1, U64>>>(), "-1/18446744073709551616");
+//!
+//! // Formatting options.
+//! type A = rational!(P7, U4);
+//! assert_eq!(format!("{:x}", DisplayType::<P255>::new()), "ff");
+//! assert_eq!(format!("{:b}", DisplayType::<A>::new()), "111/100");
+//! assert_eq!(format!("{:#}", DisplayType::<U2>::new()), "UInt<UInt<UTerm, B1>, B0>");
+//! assert_eq!(format!("{:.3}", DisplayType::<A>::new()), "1.750");
+//! assert_eq!(format!("{}", DisplayType::<A>::with(TypeFormat::new().mixed())), "1 3/4");
+//! assert_eq!(format!("{}", DisplayType::<A>::with(TypeFormat::new().latex())), "\\frac{7}{4}");
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Binary, Display, LowerHex, Octal, UpperHex};
+use std::marker::PhantomData;
+
+use typenum::{ATerm, Bit, Equal, Greater, Integer, Less, NInt, NonZero, PInt, TArr, UInt, UTerm, Unsigned, B0, B1, Z0};
 
 use crate::{CrossInt, CrossRational, R};
 
 /// Same as [`Display`](std::fmt::Display), but formats a type and not a value.
+///
+/// With the `derive` feature, it can be derived for types generic over typenum types.
+#[cfg_attr(feature = "derive", doc = "See [the derive macro](macro@crate::TypeDisplay) for the attributes.")]
+///
+/// Example:
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use std::marker::PhantomData;
+/// use crate::extended_typenum::*;
+///
+/// #[derive(TypeDisplay)]
+/// struct Matrix<Rows, Cols>(PhantomData<(Rows, Cols)>);
+///
+/// #[derive(TypeDisplay)]
+/// #[type_display(rename = "Q")]
+/// struct Quantity<'a, #[type_display(rename = "scale")] Scale, #[type_display(hide)] T, const EXP: i32> {
+///     value: &'a T,
+///     scale: PhantomData<Scale>,
+/// }
+///
+/// assert_eq!(type_string::<Matrix<U3, Sum<U2, U2>>>(), "Matrix<3, 4>");
+/// assert_eq!(type_string::<Quantity<rational!(P1, U1000), f64, 2>>(), "Q<scale = 1/1000, 2>");
+/// assert_eq!(format!("{:x}", DisplayType::<Matrix<U255, U16>>::new()), "Matrix<ff, 10>");
+/// assert_eq!(format!("{:x}", DisplayType::<Quantity<U10, f64, -30>>::new()), "Q<scale = a, -1e>");
+/// ```
 pub trait TypeDisplay {
     /// Formats the type using the given formatter.
     fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+
+    /// Formats the type using the given formatter and options.
+    ///
+    /// Types that don't support options can rely on the default implementation, which ignores them.
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        let _ = format;
+        Self::fmt(f)
+    }
+}
+
+/// Maximum number of fractional digits written with [TypeFormat::repeating].
+pub const MAX_REPEATING_DIGITS: usize = 1024;
+
+/// Options for [TypeDisplay::fmt_with], on top of the formatter flags.
+///
+/// The radix is set by the formatting trait when using [DisplayType], or with [TypeFormat::radix].
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub struct TypeFormat {
+    /// Radix of the numbers, between 2 and 36.
+    radix: u32,
+    /// Whether digits above 9 are uppercase.
+    uppercase: bool,
+    /// Whether rationals are formatted as mixed numbers, like `1 3/4`.
+    pub mixed: bool,
+    /// Whether rationals are formatted as LaTeX fractions, like `\frac{7}{4}`.
+    pub latex: bool,
+    /// Whether rationals are formatted as exact decimals with their repeating part in parentheses, like `0.1(6)`.
+    pub repeating: bool,
+}
+impl TypeFormat {
+    /// Creates the default options: decimal numbers and `N/D` rationals.
+    pub const fn new() -> Self {
+        Self { radix: 10, uppercase: false, mixed: false, latex: false, repeating: false }
+    }
+
+    /// Enables mixed numbers.
+    pub const fn mixed(self) -> Self {
+        Self { mixed: true, ..self }
+    }
+
+    /// Enables LaTeX fractions.
+    pub const fn latex(self) -> Self {
+        Self { latex: true, ..self }
+    }
+
+    /// Enables exact decimals with their repeating part in parentheses.
+    ///
+    /// It takes precedence over mixed numbers and LaTeX fractions. See also [DecimalExpansion](crate::DecimalExpansion).
+    /// Rationals with more than [MAX_REPEATING_DIGITS] fractional digits are formatted as fractions instead.
+    pub const fn repeating(self) -> Self {
+        Self { repeating: true, ..self }
+    }
+
+    /// Sets the radix of the numbers.
+    ///
+    /// # Panics
+    ///
+    /// If the radix is not between 2 and 36.
+    ///
+    /// Example:
+    /// ```
+    /// use crate::extended_typenum::*;
+    ///
+    /// assert_eq!(DisplayType::<U35>::with(TypeFormat::new().radix(36, true)).to_string(), "Z");
+    /// // TypeFormat::new().radix(37, false); Panics.
+    /// ```
+    pub const fn radix(self, radix: u32, uppercase: bool) -> Self {
+        assert!(2 <= radix && radix <= 36, "the radix must be between 2 and 36");
+        Self { radix, uppercase, ..self }
+    }
+}
+impl Default for TypeFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implements [TypeDisplay::fmt] with the default options.
+macro_rules! fmt_default {
+    () => {
+        fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            Self::fmt_with(f, &TypeFormat::new())
+        }
+    };
 }
 
 impl TypeDisplay for B0 {
@@ -21,57 +174,620 @@ impl TypeDisplay for B1 {
 }
 
 impl TypeDisplay for UTerm {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "0")
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, _: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "UTerm")
+        } else {
+            write!(f, "0")
+        }
     }
 }
 
-impl<U, B> TypeDisplay for UInt<U, B>
+/// Numbers of any size are supported, even beyond [usize].
+impl<U: Unsigned + TypeDisplay, B: Bit + TypeDisplay> TypeDisplay for UInt<U, B>
 where
-    UInt<U, B>: Unsigned,
+    UInt<U, B>: PrivateMagnitude,
 {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", <UInt<U, B> as Unsigned>::USIZE)
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "UInt<")?;
+            U::fmt_with(f, format)?;
+            write!(f, ", ")?;
+            B::fmt_with(f, format)?;
+            write!(f, ">")
+        } else {
+            fmt_integer::<Self>(f, format)
+        }
     }
 }
 
 impl TypeDisplay for Z0 {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "0")
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, _: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "Z0")
+        } else {
+            write!(f, "0")
+        }
     }
 }
 
-impl<U: TypeDisplay + Unsigned + NonZero> TypeDisplay for PInt<U> {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        U::fmt(f)
+impl<U: TypeDisplay + Unsigned + NonZero + PrivateMagnitude> TypeDisplay for PInt<U> {
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "PInt<")?;
+            U::fmt_with(f, format)?;
+            write!(f, ">")
+        } else {
+            fmt_integer::<Self>(f, format)
+        }
+    }
+}
+
+impl<U: TypeDisplay + Unsigned + NonZero + PrivateMagnitude> TypeDisplay for NInt<U> {
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "NInt<")?;
+            U::fmt_with(f, format)?;
+            write!(f, ">")
+        } else {
+            fmt_integer::<Self>(f, format)
+        }
+    }
+}
+
+/// Integral rationals are formatted as integers.
+impl<N, D> TypeDisplay for R<N, D>
+where
+    N: TypeDisplay + Integer + PrivateMagnitude,
+    D: TypeDisplay + Unsigned + NonZero + PrivateMagnitude,
+{
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "R<")?;
+            N::fmt_with(f, format)?;
+            write!(f, ", ")?;
+            D::fmt_with(f, format)?;
+            write!(f, ">")
+        } else {
+            fmt_ratio(f, format, N::NEGATIVE, magnitude::<N>(), magnitude::<D>())
+        }
     }
 }
 
-impl<U: TypeDisplay + Unsigned + NonZero> TypeDisplay for NInt<U> {
+impl<I: TypeDisplay> TypeDisplay for CrossInt<I> {
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "CrossInt<")?;
+            I::fmt_with(f, format)?;
+            write!(f, ">")
+        } else {
+            I::fmt_with(f, format)
+        }
+    }
+}
+
+impl<R: TypeDisplay> TypeDisplay for CrossRational<R> {
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "CrossRational<")?;
+            R::fmt_with(f, format)?;
+            write!(f, ">")
+        } else {
+            R::fmt_with(f, format)
+        }
+    }
+}
+
+impl TypeDisplay for Less {
     fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "-")?;
-        U::fmt(f)
+        write!(f, "Less")
     }
 }
 
-impl<N: TypeDisplay + Integer, D: TypeDisplay + Unsigned + NonZero> TypeDisplay for R<N, D> {
+impl TypeDisplay for Equal {
     fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        N::fmt(f)?;
-        write!(f, "/")?;
-        D::fmt(f)
+        write!(f, "Equal")
     }
 }
 
-impl<I: TypeDisplay> TypeDisplay for CrossInt<I>
-{
+impl TypeDisplay for Greater {
     fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        I::fmt(f)
+        write!(f, "Greater")
     }
 }
 
-impl<R: TypeDisplay> TypeDisplay for CrossRational<R>
-{
+impl TypeDisplay for ATerm {
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, _: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "ATerm")
+        } else {
+            write!(f, "[]")
+        }
+    }
+}
+
+impl<V: TypeDisplay, A: TypeDisplay + PrivateListTail> TypeDisplay for TArr<V, A> {
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "TArr<")?;
+            V::fmt_with(f, format)?;
+            write!(f, ", ")?;
+            A::fmt_with(f, format)?;
+            write!(f, ">")
+        } else {
+            write!(f, "[")?;
+            V::fmt_with(f, format)?;
+            A::fmt_tail(f, format)?;
+            write!(f, "]")
+        }
+    }
+}
+
+/// Formats the remaining elements of a list, each preceded by a comma.
+#[doc(hidden)]
+pub trait PrivateListTail {
+    fn fmt_tail(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result;
+}
+impl PrivateListTail for ATerm {
+    fn fmt_tail(_: &mut std::fmt::Formatter<'_>, _: &TypeFormat) -> std::fmt::Result {
+        Ok(())
+    }
+}
+impl<V: TypeDisplay, A: PrivateListTail> PrivateListTail for TArr<V, A> {
+    fn fmt_tail(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        write!(f, ", ")?;
+        V::fmt_with(f, format)?;
+        A::fmt_tail(f, format)
+    }
+}
+
+impl TypeDisplay for () {
     fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        R::fmt(f)
+        write!(f, "()")
+    }
+}
+
+impl<A: TypeDisplay> TypeDisplay for (A,) {
+    fmt_default!();
+
+    fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+        write!(f, "(")?;
+        A::fmt_with(f, format)?;
+        write!(f, ",)")
+    }
+}
+
+macro_rules! impl_tuple {
+    ($first:ident $(, $rest:ident)+) => {
+        impl<$first: TypeDisplay $(, $rest: TypeDisplay)+> TypeDisplay for ($first, $($rest),+) {
+            fmt_default!();
+
+            fn fmt_with(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+                write!(f, "(")?;
+                $first::fmt_with(f, format)?;
+                $(
+                    write!(f, ", ")?;
+                    $rest::fmt_with(f, format)?;
+                )+
+                write!(f, ")")
+            }
+        }
+    };
+}
+
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+impl_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Formats the const parameters of the derived [TypeDisplay] implementations, with the radix of the options.
+#[doc(hidden)]
+pub trait PrivateConstDisplay {
+    fn fmt_const(&self, f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result;
+}
+macro_rules! impl_const_display {
+    ($($T:ty),* => |$n:ident| $sign_magnitude:expr) => {
+        $(
+            impl PrivateConstDisplay for $T {
+                // The cast is needed for the other types.
+                #[allow(clippy::unnecessary_cast)]
+                fn fmt_const(&self, f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+                    let $n = *self;
+                    let (negative, magnitude): (bool, u128) = $sign_magnitude;
+                    let bits = (0..128).rev().map(|i| (magnitude >> i) & 1 == 1).collect::<Vec<_>>();
+                    let sign = if negative { "-" } else { "" };
+                    write!(f, "{sign}{}", BigUint::from_bits(&bits).to_string_radix(format.radix, format.uppercase))
+                }
+            }
+        )*
+    };
+}
+impl_const_display!(u8, u16, u32, u64, u128, usize => |n| (false, n as u128));
+impl_const_display!(i8, i16, i32, i64, i128, isize => |n| (n < 0, n.unsigned_abs() as u128));
+impl PrivateConstDisplay for bool {
+    fn fmt_const(&self, f: &mut std::fmt::Formatter<'_>, _: &TypeFormat) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+impl PrivateConstDisplay for char {
+    fn fmt_const(&self, f: &mut std::fmt::Formatter<'_>, _: &TypeFormat) -> std::fmt::Result {
+        write!(f, "{self}")
     }
-}
\ No newline at end of file
+}
+
+/// Sign and bits of the absolute value of a number.
+#[doc(hidden)]
+pub trait PrivateMagnitude {
+    const NEGATIVE: bool;
+    /// Pushes the bits of the absolute value, most significant first.
+    fn push_bits(bits: &mut Vec<bool>);
+}
+impl PrivateMagnitude for UTerm {
+    const NEGATIVE: bool = false;
+    fn push_bits(_: &mut Vec<bool>) {}
+}
+impl<U: PrivateMagnitude, B: Bit> PrivateMagnitude for UInt<U, B> {
+    const NEGATIVE: bool = false;
+    fn push_bits(bits: &mut Vec<bool>) {
+        U::push_bits(bits);
+        bits.push(B::BOOL);
+    }
+}
+impl PrivateMagnitude for Z0 {
+    const NEGATIVE: bool = false;
+    fn push_bits(_: &mut Vec<bool>) {}
+}
+impl<U: PrivateMagnitude + Unsigned + NonZero> PrivateMagnitude for PInt<U> {
+    const NEGATIVE: bool = false;
+    fn push_bits(bits: &mut Vec<bool>) {
+        U::push_bits(bits);
+    }
+}
+impl<U: PrivateMagnitude + Unsigned + NonZero> PrivateMagnitude for NInt<U> {
+    const NEGATIVE: bool = true;
+    fn push_bits(bits: &mut Vec<bool>) {
+        U::push_bits(bits);
+    }
+}
+
+fn magnitude<T: PrivateMagnitude>() -> BigUint {
+    let mut bits = Vec::new();
+    T::push_bits(&mut bits);
+    BigUint::from_bits(&bits)
+}
+
+fn fmt_integer<T: PrivateMagnitude>(f: &mut std::fmt::Formatter<'_>, format: &TypeFormat) -> std::fmt::Result {
+    let sign = if T::NEGATIVE { "-" } else { "" };
+    write!(f, "{sign}{}", magnitude::<T>().to_string_radix(format.radix, format.uppercase))
+}
+
+fn fmt_ratio(
+    f: &mut std::fmt::Formatter<'_>,
+    format: &TypeFormat,
+    negative: bool,
+    num: BigUint,
+    den: BigUint,
+) -> std::fmt::Result {
+    let digits = |n: &BigUint| n.to_string_radix(format.radix, format.uppercase);
+
+    if let Some(precision) = f.precision() {
+        let mut scaled = num;
+        for _ in 0..precision {
+            scaled.mul_add_small(format.radix, 0);
+        }
+        let (mut quotient, mut remainder) = scaled.div_rem(&den);
+        remainder.mul_add_small(2, 0);
+        if remainder >= den {
+            quotient.mul_add_small(1, 1);
+        }
+        let sign = if negative && !quotient.is_zero() { "-" } else { "" };
+        let digits = format!("{:0>width$}", digits(&quotient), width = precision + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - precision);
+        return if precision == 0 {
+            write!(f, "{sign}{whole}")
+        } else {
+            write!(f, "{sign}{whole}.{fraction}")
+        };
+    }
+
+    let sign = if negative { "-" } else { "" };
+    let (whole, remainder) = num.div_rem(&den);
+    if remainder.is_zero() {
+        return write!(f, "{sign}{}", digits(&whole));
+    }
+    if format.repeating {
+        if let Some(fraction) = repeating_fraction(remainder.clone(), &den, format) {
+            return write!(f, "{sign}{}.{fraction}", digits(&whole));
+        }
+    }
+    let (whole, num) = if format.mixed && !whole.is_zero() {
+        (Some(digits(&whole)), remainder)
+    } else {
+        (None, num)
+    };
+    match (whole, format.latex) {
+        (None, false) => write!(f, "{sign}{}/{}", digits(&num), digits(&den)),
+        (Some(whole), false) => write!(f, "{sign}{whole} {}/{}", digits(&num), digits(&den)),
+        (None, true) => write!(f, "{sign}\\frac{{{}}}{{{}}}", digits(&num), digits(&den)),
+        (Some(whole), true) => write!(f, "{sign}{whole}\\frac{{{}}}{{{}}}", digits(&num), digits(&den)),
+    }
+}
+
+/// Fractional digits of `remainder / den`, with the repeating part in parentheses.
+///
+/// `None` if there are more than [MAX_REPEATING_DIGITS] digits.
+fn repeating_fraction(mut remainder: BigUint, den: &BigUint, format: &TypeFormat) -> Option<String> {
+    let mut seen = HashMap::new();
+    let mut fraction = String::new();
+    while !remainder.is_zero() {
+        if let Some(&start) = seen.get(&remainder) {
+            return Some(format!("{}({})", &fraction[..start], &fraction[start..]));
+        }
+        if seen.len() == MAX_REPEATING_DIGITS {
+            return None;
+        }
+        seen.insert(remainder.clone(), fraction.len());
+        remainder.mul_add_small(format.radix, 0);
+        let (digit, next) = remainder.div_rem(den);
+        fraction.push_str(&digit.to_string_radix(format.radix, format.uppercase));
+        remainder = next;
+    }
+    Some(fraction)
+}
+
+/// Formats a number given by the bits of its numerator and denominator, most significant first, like [TypeDisplay] does.
+///
+/// Shared with [pretty_type_name](crate::pretty_type_name).
+pub(crate) fn number_string(negative: bool, num: &[bool], den: &[bool]) -> String {
+    struct Number<'a>(bool, &'a [bool], &'a [bool]);
+    impl Display for Number<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fmt_ratio(f, &TypeFormat::new(), self.0, BigUint::from_bits(self.1), BigUint::from_bits(self.2))
+        }
+    }
+    Number(negative, num, den).to_string()
+}
+
+/// Arbitrary precision natural number, with little-endian limbs and no trailing zero limb.
+#[derive(Clone, Hash, PartialEq, Eq)]
+struct BigUint(Vec<u32>);
+
+impl BigUint {
+    fn from_bits(bits: &[bool]) -> Self {
+        let mut result = Self(Vec::new());
+        for &bit in bits {
+            result.mul_add_small(2, bit as u32);
+        }
+        result
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// `self = self * mul + add`
+    fn mul_add_small(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for limb in self.0.iter_mut() {
+            let value = *limb as u64 * mul as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            self.0.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    /// `self = self / div`, returning the remainder.
+    fn div_rem_small(&mut self, div: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.0.iter_mut().rev() {
+            let value = (remainder << 32) | *limb as u64;
+            *limb = (value / div as u64) as u32;
+            remainder = value % div as u64;
+        }
+        self.normalize();
+        remainder as u32
+    }
+
+    /// Schoolbook binary long division.
+    fn div_rem(&self, div: &Self) -> (Self, Self) {
+        let mut quotient = Self(Vec::new());
+        let mut remainder = Self(Vec::new());
+        for i in (0..self.0.len() * 32).rev() {
+            remainder.mul_add_small(2, (self.0[i / 32] >> (i % 32)) & 1);
+            let bit = remainder >= *div;
+            if bit {
+                remainder.sub_assign(div);
+            }
+            quotient.mul_add_small(2, bit as u32);
+        }
+        (quotient, remainder)
+    }
+
+    /// `self = self - other`, assuming `self >= other`.
+    fn sub_assign(&mut self, other: &Self) {
+        let mut borrow = 0i64;
+        for (i, limb) in self.0.iter_mut().enumerate() {
+            let value = *limb as i64 - other.0.get(i).copied().unwrap_or(0) as i64 - borrow;
+            borrow = (value < 0) as i64;
+            *limb = value.rem_euclid(1 << 32) as u32;
+        }
+        self.normalize();
+    }
+
+    fn normalize(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    /// The radix is between 2 and 36, as checked by [TypeFormat::radix].
+    fn to_string_radix(&self, radix: u32, uppercase: bool) -> String {
+        const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+        let mut n = self.clone();
+        let mut digits = Vec::new();
+        loop {
+            let digit = DIGITS[n.div_rem_small(radix) as usize] as char;
+            digits.push(if uppercase { digit.to_ascii_uppercase() } else { digit });
+            if n.is_zero() {
+                return digits.iter().rev().collect();
+            }
+        }
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.len().cmp(&other.0.len()).then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+/// A zero-sized value implementing [Display] with the [TypeDisplay] of `T`.
+///
+/// It also implements [LowerHex], [UpperHex], [Binary] and [Octal].
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// type A = Sum<U2, U3>;
+/// assert_eq!(format!("{}", DisplayType::<A>::new()), "5");
+/// assert_eq!(format!("{}", DisplayType::<(A, rational!(P1, U2))>::new()), "(5, 1/2)");
+/// assert_eq!(format!("{:X}", DisplayType::<tarr![P10, N255]>::new()), "[A, -FF]");
+/// assert_eq!(format!("{:o}", DisplayType::<U8>::new()), "10");
+/// assert_eq!(format!("{:#}", DisplayType::<rational!(N1, U2)>::new()), "R<NInt<UInt<UTerm, B1>>, UInt<UInt<UTerm, B1>, B0>>");
+/// assert_eq!(format!("{:.2}", DisplayType::<rational!(N2, U3)>::new()), "-0.67");
+/// assert_eq!(format!("{:.0}", DisplayType::<rational!(P5, U2)>::new()), "3");
+/// ```
+pub struct DisplayType<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> DisplayType<T> {
+    /// Creates a new wrapper.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Creates a wrapper formatting with the given options.
+    ///
+    /// Example:
+    /// ```
+    /// use crate::extended_typenum::*;
+    ///
+    /// type A = rational!(N7, U4);
+    /// assert_eq!(DisplayType::<A>::with(TypeFormat::new().mixed()).to_string(), "-1 3/4");
+    /// assert_eq!(DisplayType::<A>::with(TypeFormat::new().latex()).to_string(), "-\\frac{7}{4}");
+    /// assert_eq!(DisplayType::<A>::with(TypeFormat::new().mixed().latex()).to_string(), "-1\\frac{3}{4}");
+    /// assert_eq!(DisplayType::<rational!(P3, U4)>::with(TypeFormat::new().mixed()).to_string(), "3/4");
+    /// assert_eq!(DisplayType::<U4>::with(TypeFormat::new().latex()).to_string(), "4");
+    /// assert_eq!(DisplayType::<A>::with(TypeFormat::new().repeating()).to_string(), "-1.75");
+    /// assert_eq!(DisplayType::<rational!(P5, U3)>::with(TypeFormat::new().repeating()).to_string(), "1.(6)");
+    /// assert_eq!(format!("{:b}", DisplayType::<rational!(P1, U3)>::with(TypeFormat::new().repeating())), "0.(01)");
+    ///
+    /// // Too many digits before the repeating part ends.
+    /// type Prime = Sub1<Shleft<U1, U61>>;
+    /// assert_eq!(DisplayType::<R<P1, Prime>>::with(TypeFormat::new().repeating()).to_string(), "1/2305843009213693951");
+    /// ```
+    pub fn with(format: TypeFormat) -> DisplayTypeWith<T> {
+        DisplayTypeWith { format, phantom: PhantomData }
+    }
+}
+
+impl<T: ?Sized> Default for DisplayType<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Clone for DisplayType<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for DisplayType<T> {}
+
+/// A value implementing [Display] with the [TypeDisplay] of `T` and the given [TypeFormat].
+///
+/// Created with [DisplayType::with]. The radix of the options is overridden by [LowerHex], [UpperHex], [Binary] and [Octal].
+pub struct DisplayTypeWith<T: ?Sized> {
+    format: TypeFormat,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ?Sized> Clone for DisplayTypeWith<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for DisplayTypeWith<T> {}
+
+macro_rules! impl_formatting {
+    ($Wrapper:ident, $format:ident => $get:expr) => {
+        impl_formatting!(@impl $Wrapper, $format => $get, Display, |format: TypeFormat| format);
+        impl_formatting!(@impl $Wrapper, $format => $get, std::fmt::Debug, |format: TypeFormat| format);
+        impl_formatting!(@impl $Wrapper, $format => $get, LowerHex, |format: TypeFormat| format.radix(16, false));
+        impl_formatting!(@impl $Wrapper, $format => $get, UpperHex, |format: TypeFormat| format.radix(16, true));
+        impl_formatting!(@impl $Wrapper, $format => $get, Binary, |format: TypeFormat| format.radix(2, false));
+        impl_formatting!(@impl $Wrapper, $format => $get, Octal, |format: TypeFormat| format.radix(8, false));
+    };
+    (@impl $Wrapper:ident, $format:ident => $get:expr, $Trait:path, $radix:expr) => {
+        impl<T: TypeDisplay + ?Sized> $Trait for $Wrapper<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let $format = self;
+                T::fmt_with(f, &($radix)($get))
+            }
+        }
+    };
+}
+
+impl_formatting!(DisplayType, _wrapper => TypeFormat::new());
+impl_formatting!(DisplayTypeWith, wrapper => wrapper.format);
+
+/// Formats a type with [TypeDisplay] into a [String].
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_eq!(type_string::<Diff<P2, P5>>(), "-3");
+/// assert_eq!(type_string::<tarr![]>(), "[]");
+/// ```
+pub fn type_string<T: TypeDisplay + ?Sized>() -> String {
+    DisplayType::<T>::new().to_string()
+}
@@ -0,0 +1,157 @@
+//! Compile-time names of types, through the [TypeName] trait.
+//!
+//! The names are the same as the ones of [TypeDisplay](crate::TypeDisplay) with the default options,
+//! but they are `&'static str` constants, usable in `const` items and `const fn`.
+//! Numbers are converted to their decimal digits with [ToDigits](crate::ToDigits) at the type level.
+//!
+//! Names are limited to [TYPE_NAME_CAPACITY] bytes, longer names fail to compile.
+//!
+//! Example:
+//! ```
+//! use crate::extended_typenum::*;
+//!
+//! const NAME: &str = <rational!(N3, U4) as TypeName>::NAME;
+//! assert_eq!(NAME, "-3/4");
+//!
+//! const fn check<A: TypeName>(value: usize) {
+//!     if value > 10 {
+//!         panic!("{}", A::NAME);
+//!     }
+//! }
+//! check::<U10>(5);
+//! ```
+
+use typenum::{ATerm, Bit, Integer, NInt, NonZero, PInt, TArr, UInt, UTerm, Unsigned, B0, B1, U10, Z0};
+
+use crate::{CrossInt, CrossRational, IntoDigits, MsbFirst, ToDigits, R};
+
+/// Maximum length in bytes of a [TypeName::NAME].
+pub const TYPE_NAME_CAPACITY: usize = 128;
+
+/// Name of a type, computed at compile time.
+///
+/// Example:
+/// ```
+/// use crate::extended_typenum::*;
+///
+/// assert_eq!(U0::NAME, "0");
+/// assert_eq!(<Prod<U125, U8> as TypeName>::NAME, "1000");
+/// assert_eq!(N42::NAME, "-42");
+/// assert_eq!(<rational!(P6, U2) as TypeName>::NAME, "3");
+/// assert_eq!(<rational!(P1, U3) as TypeName>::NAME, "1/3");
+/// assert_eq!(True::NAME, "B1");
+/// assert_eq!(<CrossInt<N7> as TypeName>::NAME, "-7");
+/// assert_eq!(<CrossRational<rational!(N1, U2)> as TypeName>::NAME, "-1/2");
+/// ```
+pub trait TypeName {
+    /// The name.
+    const NAME: &'static str = Self::NAME_BUFFER.as_str();
+
+    #[doc(hidden)]
+    const NAME_BUFFER: PrivateNameBuffer;
+}
+
+/// Fixed capacity buffer used to build the names in `const` contexts.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct PrivateNameBuffer {
+    bytes: [u8; TYPE_NAME_CAPACITY],
+    len: usize,
+}
+impl PrivateNameBuffer {
+    pub const fn from_text(s: &str) -> Self {
+        Self { bytes: [0; TYPE_NAME_CAPACITY], len: 0 }.concat_bytes(s.as_bytes())
+    }
+
+    pub const fn concat(self, other: &Self) -> Self {
+        let (bytes, _) = other.bytes.split_at(other.len);
+        self.concat_bytes(bytes)
+    }
+
+    const fn concat_bytes(mut self, bytes: &[u8]) -> Self {
+        if self.len + bytes.len() > TYPE_NAME_CAPACITY {
+            panic!("type name too long");
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            self.bytes[self.len + i] = bytes[i];
+            i += 1;
+        }
+        self.len += bytes.len();
+        self
+    }
+
+    const fn is_one(&self) -> bool {
+        self.len == 1 && self.bytes[0] == b'1'
+    }
+
+    pub const fn as_str(&'static self) -> &'static str {
+        let (bytes, _) = self.bytes.split_at(self.len);
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => panic!("invalid type name"),
+        }
+    }
+}
+
+impl TypeName for B0 {
+    const NAME_BUFFER: PrivateNameBuffer = PrivateNameBuffer::from_text("B0");
+}
+
+impl TypeName for B1 {
+    const NAME_BUFFER: PrivateNameBuffer = PrivateNameBuffer::from_text("B1");
+}
+
+impl TypeName for UTerm {
+    const NAME_BUFFER: PrivateNameBuffer = PrivateNameBuffer::from_text("0");
+}
+
+impl<U: Unsigned, B: Bit> TypeName for UInt<U, B>
+where
+    UInt<U, B>: IntoDigits<U10, MsbFirst>,
+    ToDigits<UInt<U, B>, U10>: PrivateDigitsName,
+{
+    const NAME_BUFFER: PrivateNameBuffer = <ToDigits<UInt<U, B>, U10> as PrivateDigitsName>::NAME_BUFFER;
+}
+
+/// Name of a list of decimal digits.
+#[doc(hidden)]
+pub trait PrivateDigitsName {
+    const NAME_BUFFER: PrivateNameBuffer;
+}
+impl PrivateDigitsName for ATerm {
+    const NAME_BUFFER: PrivateNameBuffer = PrivateNameBuffer::from_text("");
+}
+impl<V: Unsigned, A: PrivateDigitsName> PrivateDigitsName for TArr<V, A> {
+    const NAME_BUFFER: PrivateNameBuffer =
+        PrivateNameBuffer::from_text("").concat_bytes(&[b'0' + V::U8]).concat(&A::NAME_BUFFER);
+}
+
+impl TypeName for Z0 {
+    const NAME_BUFFER: PrivateNameBuffer = PrivateNameBuffer::from_text("0");
+}
+
+impl<U: Unsigned + NonZero + TypeName> TypeName for PInt<U> {
+    const NAME_BUFFER: PrivateNameBuffer = U::NAME_BUFFER;
+}
+
+impl<U: Unsigned + NonZero + TypeName> TypeName for NInt<U> {
+    const NAME_BUFFER: PrivateNameBuffer = PrivateNameBuffer::from_text("-").concat(&U::NAME_BUFFER);
+}
+
+/// Integral rationals are named as integers.
+impl<N: Integer + TypeName, D: Unsigned + NonZero + TypeName> TypeName for R<N, D> {
+    const NAME_BUFFER: PrivateNameBuffer = if D::NAME_BUFFER.is_one() {
+        N::NAME_BUFFER
+    } else {
+        N::NAME_BUFFER.concat(&PrivateNameBuffer::from_text("/")).concat(&D::NAME_BUFFER)
+    };
+}
+
+impl<I: TypeName> TypeName for CrossInt<I> {
+    const NAME_BUFFER: PrivateNameBuffer = I::NAME_BUFFER;
+}
+
+impl<R: TypeName> TypeName for CrossRational<R> {
+    const NAME_BUFFER: PrivateNameBuffer = R::NAME_BUFFER;
+}
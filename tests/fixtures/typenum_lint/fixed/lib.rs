@@ -0,0 +1,27 @@
+use extended_typenum::*;
+
+mod units;
+
+/// Not checked in comments: `R<P6, U8>`.
+pub type Half = rational!(P1, U2);
+pub type Quarter = R<P1, U4>;
+pub type MinusTwo = rational!(N2);
+pub type Zero = rational!(Z0);
+pub type Canonical = (R<Z0, U1>, R<N3, U4>, rational!(P6, U8));
+pub type Qualified = extended_typenum::rational!(P1, U10);
+pub type Spaced = rational!(P3, U4);
+pub type Big = rational!(PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>, B0>, B1>, B0>, B0>, B0>, B0>, B1>, B0>, B0>, B1>>);
+pub type Nested = Vec<(rational!(P1), Option<rational!(N2, U3)>)>;
+
+/* Block comments /* even nested */ R<P2, U4> */
+pub const NAME: &str = "R<P2, U4>";
+pub const RAW: &str = r#"R<P2, "U4">"#;
+pub const QUOTE: char = '"';
+
+pub fn generic<'a, N: Integer>(_: &'a R<N, U4>) -> rational!(P2) {
+    R::new()
+}
+
+pub struct R2<N, D>(N, D);
+pub type Other = (R2<P2, U4>, my::R<P2, U4>);
+pub type Unicode<Ä, Ü> = (R<Ä, U2>, R<P2, Ü>);
@@ -0,0 +1,5 @@
+use extended_typenum::*;
+
+pub type Invalid = R<P1, U0>;
+pub type Third = rational!(P1, U3);
+pub type Tiny = rational!(P1, U9223372036854775807);
@@ -0,0 +1,30 @@
+use extended_typenum::*;
+
+mod units;
+
+/// Not checked in comments: `R<P6, U8>`.
+pub type Half = R<P2, U4>;
+pub type Quarter = R<P1, U4>;
+pub type MinusTwo = R<N6, U3>;
+pub type Zero = R<Z0, U5>;
+pub type Canonical = (R<Z0, U1>, R<N3, U4>, rational!(P6, U8));
+pub type Qualified = extended_typenum::R<P10, U100>;
+pub type Spaced = R<
+    P9,
+    U12,
+>;
+pub type Big = R<P1000000, U64>;
+pub type Nested = Vec<(R<P2, U2>, Option<R<N4, U6>>)>;
+
+/* Block comments /* even nested */ R<P2, U4> */
+pub const NAME: &str = "R<P2, U4>";
+pub const RAW: &str = r#"R<P2, "U4">"#;
+pub const QUOTE: char = '"';
+
+pub fn generic<'a, N: Integer>(_: &'a R<N, U4>) -> R<P4, U2> {
+    R::new()
+}
+
+pub struct R2<N, D>(N, D);
+pub type Other = (R2<P2, U4>, my::R<P2, U4>);
+pub type Unicode<Ä, Ü> = (R<Ä, U2>, R<P2, Ü>);
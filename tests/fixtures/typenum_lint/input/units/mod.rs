@@ -0,0 +1,5 @@
+use extended_typenum::*;
+
+pub type Invalid = R<P1, U0>;
+pub type Third = R<P3, U9>;
+pub type Tiny = R<P2, U18446744073709551614>;
@@ -0,0 +1,72 @@
+//! Runs the `typenum-calc` binary on a few expressions.
+
+use std::process::Command;
+
+fn typenum_calc(args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_typenum-calc")).args(args).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    (output.status.success(), stdout, stderr)
+}
+
+fn value(args: &[&str]) -> String {
+    let (success, stdout, stderr) = typenum_calc(args);
+    assert!(success, "{stderr}");
+    stdout
+}
+
+#[test]
+fn formats() {
+    assert_eq!(value(&["rational!(P6, U8) * U3 - N2"]), "17/4\n");
+    assert_eq!(value(&["--alias", "-7/12"]), "rational!(N7, U12)\n");
+    assert_eq!(value(&["--alias", "6/3"]), "rational!(P2)\n");
+    assert_eq!(value(&["--alias", "U3", "-", "U5"]), "N2\n");
+    assert_eq!(value(&["--alias", "P2000 + 48"]), "P2048\n");
+    assert_eq!(value(&["--alias", "P2000"]), "PInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>, B1>, B0>, B1>, B0>, B0>, B0>, B0>>\n");
+    // The unsigned aliases go up to `10^19`, the signed ones stop at `i64::MAX`.
+    assert_eq!(value(&["--alias", "1/10000000000000000000"]), "rational!(P1, U10000000000000000000)\n");
+    assert_eq!(value(&["--alias", "rational!(P1, U9223372036854775808)"]), "rational!(P1, U9223372036854775808)\n");
+    assert_eq!(value(&["--alias", "rational!(U9223372036854775807)"]), "rational!(P9223372036854775807)\n");
+    assert_eq!(value(&["--type", "-3/4"]), "R<NInt<UInt<UInt<UTerm, B1>, B1>>, UInt<UInt<UInt<UTerm, B1>, B0>, B0>>\n");
+    assert_eq!(value(&["--type", "Z0"]), "Z0\n");
+}
+
+#[test]
+fn semantics() {
+    // Integers are truncated like `CrossInt`.
+    assert_eq!(value(&["P7 / P2"]), "3\n");
+    assert_eq!(value(&["N7 / P2"]), "-3\n");
+    assert_eq!(value(&["N7 % P2"]), "-1\n");
+    assert_eq!(value(&["P7 / (3/2)"]), "4\n");
+    assert_eq!(value(&["P7 * (3/2)"]), "10\n");
+    // Rationals are exact like `CrossRational`.
+    assert_eq!(value(&["rational!(P7) / P2"]), "7/2\n");
+    assert_eq!(value(&["(2/3) ^ -2"]), "9/4\n");
+    assert_eq!(value(&["-2 ^ 2"]), "-4\n");
+    // typenum has the negative powers of `0`, `1` and `-1`.
+    assert_eq!(value(&["N1 ^ N1"]), "-1\n");
+    assert_eq!(value(&["N1 ^ N4"]), "1\n");
+    assert_eq!(value(&["P1 ^ N3"]), "1\n");
+    assert_eq!(value(&["Z0 ^ N2"]), "0\n");
+}
+
+#[test]
+fn steps() {
+    assert_eq!(value(&["--steps", "rational!(P6, U8) * U3 - N2"]), "3/4 * 3 = 9/4\n9/4 - -2 = 17/4\n17/4\n");
+}
+
+#[test]
+fn errors() {
+    assert_eq!(typenum_calc(&["P1 + 1/2"]), (false, String::new(), "error: 1/2 is not an integer\n".to_string()));
+    assert_eq!(typenum_calc(&["1 / 0"]), (false, String::new(), "error: division by zero\n".to_string()));
+    assert_eq!(typenum_calc(&["P0"]), (false, String::new(), "error: unknown name `P0`\n".to_string()));
+    assert_eq!(typenum_calc(&["(1"]), (false, String::new(), "error: expected `)`\n".to_string()));
+    let overflow = (false, String::new(), "error: overflow\n".to_string());
+    assert_eq!(typenum_calc(&["1 / (N85070591730234615865843651857942052864 * 2)"]), overflow);
+    assert_eq!(typenum_calc(&["(N85070591730234615865843651857942052864 * 2) / -1"]), overflow);
+    assert_eq!(typenum_calc(&["P2 ^ 5000000000"]), overflow);
+    assert_eq!(
+        typenum_calc(&["P2 ^ N1"]),
+        (false, String::new(), "error: negative exponent -1 for the integer 2\n".to_string())
+    );
+}
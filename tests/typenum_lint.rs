@@ -0,0 +1,68 @@
+//! Runs the `typenum-lint` binary on a copy of a fixture source tree.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/typenum_lint");
+
+/// Copies the input tree as `src` in a fresh directory.
+fn copy_input(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("typenum_lint").join(name);
+    let _ = fs::remove_dir_all(&dir);
+    copy_dir(&Path::new(FIXTURES).join("input"), &dir.join("src"));
+    dir
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+fn typenum_lint(dir: &Path, args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_typenum-lint")).args(args).current_dir(dir).output().unwrap();
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+fn read(path: PathBuf) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn report() {
+    let dir = copy_input("report");
+    let (success, output) = typenum_lint(&dir, &["src"]);
+    assert!(!success);
+    assert_eq!(output, read(Path::new(FIXTURES).join("report.txt")));
+    // Nothing is rewritten.
+    assert_eq!(read(dir.join("src/lib.rs")), read(Path::new(FIXTURES).join("input/lib.rs")));
+}
+
+#[test]
+fn fix() {
+    let dir = copy_input("fix");
+    let (success, output) = typenum_lint(&dir, &["--fix", "src"]);
+    // The zero denominator can't be fixed.
+    assert!(!success);
+    assert_eq!(output, read(Path::new(FIXTURES).join("fix_report.txt")));
+    for file in ["lib.rs", "units/mod.rs"] {
+        assert_eq!(read(dir.join("src").join(file)), read(Path::new(FIXTURES).join("fixed").join(file)), "{file}");
+    }
+    let (_, output) = typenum_lint(&dir, &["src"]);
+    assert_eq!(output, "src/units/mod.rs:3:20: `R<P1, U0>` has a zero denominator\n");
+}
+
+#[test]
+fn crate_sources_are_simplified() {
+    let (success, output) = typenum_lint(Path::new(env!("CARGO_MANIFEST_DIR")), &["src"]);
+    assert!(success, "{output}");
+    assert_eq!(output, "");
+}
@@ -0,0 +1,68 @@
+//! Runs the `typenum-pretty` binary on recorded rustc diagnostics.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn typenum_pretty(input: &str) -> String {
+    String::from_utf8(typenum_pretty_bytes(input.as_bytes())).unwrap()
+}
+
+fn typenum_pretty_bytes(input: &[u8]) -> Vec<u8> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_typenum-pretty"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    output.stdout
+}
+
+#[test]
+fn human_diagnostics() {
+    let output = typenum_pretty(include_str!("fixtures/typenum_pretty/human.txt"));
+    assert_eq!(output, include_str!("fixtures/typenum_pretty/human.expected.txt"));
+}
+
+#[test]
+fn json_diagnostics() {
+    let output = typenum_pretty(include_str!("fixtures/typenum_pretty/json.txt"));
+    assert_eq!(output, include_str!("fixtures/typenum_pretty/json.expected.txt"));
+}
+
+#[test]
+fn source_lines_are_kept() {
+    let input = "5 |     let x: UInt<UTerm, B1> = UInt::<UTerm, B1>::new();\n  |            ^^^^^^^^^^^^^^^ `UInt<UTerm, B1>`\n";
+    let expected = "5 |     let x: UInt<UTerm, B1> = UInt::<UTerm, B1>::new();\n  |            ^^^^^^^^^^^^^^^ `1`\n";
+    assert_eq!(typenum_pretty(input), expected);
+}
+
+#[test]
+fn only_json_objects_are_json() {
+    let input = "{ UInt<UTerm, B1> } and \"UInt<UTerm, B1>\"\n{\"message\": \"UInt<UTerm, B1>\", \"children\": [], \"level\": null}\n";
+    let expected = "{ 1 } and \"1\"\n{\"message\": \"1\", \"children\": [], \"level\": null}\n";
+    assert_eq!(typenum_pretty(input), expected);
+}
+
+#[test]
+fn invalid_utf8_lines_are_kept() {
+    let output = typenum_pretty_bytes(b"UInt<UTerm, B1>\n\xff UInt<UTerm, B1>\nUInt<UTerm, B1>");
+    assert_eq!(output, b"1\n\xff UInt<UTerm, B1>\n1");
+}
+
+#[test]
+fn closed_output_is_quiet() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_typenum-pretty"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    drop(child.stdout.take());
+    // The binary may stop reading before the end.
+    let _ = child.stdin.take().unwrap().write_all("UInt<UTerm, B1>\n".repeat(100_000).as_bytes());
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), "");
+}